@@ -0,0 +1,213 @@
+use tauri::State;
+use crate::state::AppState;
+use crate::commands::common::quote_identifier;
+use mysql_async::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Serialize)]
+pub struct AutoIncrementUsage {
+    pub db: String,
+    pub table: String,
+    pub column: String,
+    pub column_type: String,
+    pub max_value: u64,
+    pub next_value: Option<u64>, // the table's AUTO_INCREMENT
+    pub used_percent: f64,
+    pub growth_per_day: Option<f64>, // from recorded samples
+    pub days_left: Option<f64>,
+    pub exhausted_at: Option<String>,
+    pub samples: usize,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct Sample {
+    time: i64, // unix millis
+    value: u64,
+}
+
+/// Samples per server (`host:port`), then per `db.table`.
+type SampleStore = HashMap<String, HashMap<String, Vec<Sample>>>;
+
+const MAX_SAMPLES: usize = 500;
+const MIN_SAMPLE_INTERVAL_MS: i64 = 60_000;
+
+fn get_samples_path() -> PathBuf {
+    PathBuf::from("app_data/auto_increment_samples.json")
+}
+
+fn ensure_app_data_dir() {
+    let path = PathBuf::from("app_data");
+    if !path.exists() {
+        let _ = fs::create_dir_all(path);
+    }
+}
+
+fn load_samples() -> SampleStore {
+    ensure_app_data_dir();
+    fs::read_to_string(get_samples_path())
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn write_samples(store: &SampleStore) -> Result<(), String> {
+    ensure_app_data_dir();
+    let json = serde_json::to_string_pretty(store).map_err(|e| e.to_string())?;
+    fs::write(get_samples_path(), json).map_err(|e| e.to_string())
+}
+
+/// Largest value an auto-increment column of this type can hold.
+fn type_max(data_type: &str, unsigned: bool) -> Option<u64> {
+    let bits = match data_type {
+        "tinyint" => 8,
+        "smallint" => 16,
+        "mediumint" => 24,
+        "int" | "integer" => 32,
+        "bigint" => 64,
+        // Floating point keys stay exact only up to their mantissa.
+        "float" => return Some(1 << 24),
+        "double" | "real" => return Some(1 << 53),
+        _ => return None,
+    };
+    Some(match (bits, unsigned) {
+        (64, true) => u64::MAX,
+        (_, true) => (1u64 << bits) - 1,
+        (_, false) => (1u64 << (bits - 1)) - 1,
+    })
+}
+
+/// Appends the current value and drops history from before a reset.
+fn record_sample(samples: &mut Vec<Sample>, now: i64, value: u64) {
+    if samples.last().is_some_and(|s| value < s.value) {
+        samples.clear();
+    }
+    if samples.last().map_or(true, |s| now - s.time >= MIN_SAMPLE_INTERVAL_MS) {
+        samples.push(Sample { time: now, value });
+    }
+    if samples.len() > MAX_SAMPLES {
+        samples.drain(..samples.len() - MAX_SAMPLES);
+    }
+}
+
+/// Values consumed per day between the oldest and newest sample.
+fn growth_per_day(samples: &[Sample]) -> Option<f64> {
+    let (first, last) = (samples.first()?, samples.last()?);
+    let days = (last.time - first.time) as f64 / 86_400_000.0;
+    (days > 0.0).then(|| (last.value - first.value) as f64 / days)
+}
+
+/// Lists auto-increment columns in one database (or all user databases) with how much of
+/// their type's range is used, most used first. Each call records the current values so
+/// later reports can estimate when the range runs out.
+#[tauri::command]
+pub async fn get_auto_increment_report(db: Option<String>, state: State<'_, AppState>) -> Result<Vec<AutoIncrementUsage>, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    // MySQL 8 caches TABLES.AUTO_INCREMENT for a day by default; MariaDB has no such variable.
+    let _ = conn.query_drop("SET SESSION information_schema_stats_expiry = 0").await;
+
+    let server: Option<(String, u64)> = conn.query_first("SELECT @@hostname, @@port").await.map_err(|e| e.to_string())?;
+    let server_key = server.map(|(host, port)| format!("{}:{}", host, port)).unwrap_or_default();
+
+    let mut sql = String::from(
+        "SELECT t.TABLE_SCHEMA, t.TABLE_NAME, c.COLUMN_NAME, c.DATA_TYPE, c.COLUMN_TYPE, t.AUTO_INCREMENT
+         FROM information_schema.TABLES t
+         JOIN information_schema.COLUMNS c ON c.TABLE_SCHEMA = t.TABLE_SCHEMA AND c.TABLE_NAME = t.TABLE_NAME
+         WHERE c.EXTRA LIKE '%auto_increment%' AND t.TABLE_TYPE = 'BASE TABLE'",
+    );
+    let params: Vec<mysql_async::Value> = match &db {
+        Some(db) => {
+            sql.push_str(" AND t.TABLE_SCHEMA = ?");
+            vec![db.clone().into()]
+        }
+        None => {
+            sql.push_str(" AND t.TABLE_SCHEMA NOT IN ('mysql', 'sys', 'information_schema', 'performance_schema')");
+            Vec::new()
+        }
+    };
+    let rows: Vec<(String, String, String, String, String, Option<u64>)> = conn.exec(sql, params).await.map_err(|e| e.to_string())?;
+
+    let now = chrono::Local::now();
+    let now_ms = now.timestamp_millis();
+    let mut store = load_samples();
+    let history = store.entry(server_key).or_default();
+
+    let mut report = Vec::new();
+    for (db, table, column, data_type, column_type, next_value) in rows {
+        let Some(max_value) = type_max(&data_type.to_lowercase(), column_type.to_lowercase().contains("unsigned")) else { continue };
+        let samples = history.entry(format!("{}.{}", db, table)).or_default();
+        if let Some(value) = next_value {
+            record_sample(samples, now_ms, value);
+        }
+
+        let used = next_value.unwrap_or(1).saturating_sub(1);
+        let growth = growth_per_day(samples);
+        let days_left = growth.filter(|g| *g > 0.0).map(|g| max_value.saturating_sub(used) as f64 / g);
+        let exhausted_at = days_left
+            .filter(|d| *d < 365.0 * 1000.0)
+            .map(|d| (now + chrono::Duration::seconds((d * 86_400.0) as i64)).to_rfc3339());
+
+        report.push(AutoIncrementUsage {
+            db,
+            table,
+            column,
+            column_type,
+            max_value,
+            next_value,
+            used_percent: used as f64 / max_value as f64 * 100.0,
+            growth_per_day: growth,
+            days_left,
+            exhausted_at,
+            samples: samples.len(),
+        });
+    }
+    write_samples(&store)?;
+
+    report.sort_by(|a, b| b.used_percent.total_cmp(&a.used_percent));
+    Ok(report)
+}
+
+/// Sets the next auto-increment value of a table. Without `value` it is reset to one past
+/// the current maximum. Values at or below the maximum are rejected because the server
+/// would silently raise them. Returns the SQL that ran.
+#[tauri::command]
+pub async fn set_auto_increment(db: String, table: String, value: Option<u64>, state: State<'_, AppState>) -> Result<String, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let column: Option<String> = conn
+        .exec_first(
+            "SELECT COLUMN_NAME FROM information_schema.COLUMNS
+             WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ? AND EXTRA LIKE '%auto_increment%'",
+            (&db, &table),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    let column = column.ok_or(format!("`{}`.`{}` has no auto-increment column", db, table))?;
+
+    let table_ref = format!("{}.{}", quote_identifier(&db), quote_identifier(&table));
+    let max: Option<u64> = conn
+        .query_first(format!("SELECT CAST(GREATEST(COALESCE(MAX({}), 0), 0) AS UNSIGNED) FROM {}", quote_identifier(&column), table_ref))
+        .await
+        .map_err(|e| e.to_string())?;
+    let lowest = max.unwrap_or(0).saturating_add(1);
+    let next = match value {
+        Some(v) if v < lowest => return Err(format!("Next value must be at least {} (current maximum of `{}` plus one)", lowest, column)),
+        Some(v) => v,
+        None => lowest,
+    };
+
+    let sql = format!("ALTER TABLE {} AUTO_INCREMENT = {}", table_ref, next);
+    conn.query_drop(&sql).await.map_err(|e| e.to_string())?;
+    Ok(sql)
+}
@@ -0,0 +1,221 @@
+use tauri::{AppHandle, Emitter, State};
+use crate::state::AppState;
+use crate::commands::columns::check_word;
+use crate::commands::common::quote_identifier;
+use mysql_async::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Event emitted after each table so the UI can show progress.
+pub const PROGRESS_EVENT: &str = "table-operation-progress";
+
+#[derive(Deserialize, Default)]
+pub struct BulkOptions {
+    pub ignore_foreign_keys: Option<bool>, // run with FOREIGN_KEY_CHECKS = 0
+    pub stop_on_error: Option<bool>,
+}
+
+#[derive(Serialize, Clone)]
+pub struct TableOperationResult {
+    pub table: String,
+    pub ok: bool,
+    pub message: Option<String>,
+    pub rows: Vec<Vec<String>>, // result set of maintenance statements
+    pub duration_ms: f64,
+}
+
+#[derive(Serialize)]
+pub struct BulkReport {
+    pub operation: String,
+    pub total: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub results: Vec<TableOperationResult>,
+    pub duration_ms: f64,
+}
+
+#[derive(Serialize, Clone)]
+struct BulkProgress {
+    operation: String,
+    index: usize,
+    total: usize,
+    result: TableOperationResult,
+}
+
+/// Runs one statement per table on a single connection, reporting progress as it goes.
+async fn run_bulk(
+    app_handle: &AppHandle,
+    state: &State<'_, AppState>,
+    operation: &str,
+    statements: Vec<(String, String)>,
+    options: BulkOptions,
+) -> Result<BulkReport, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let ignore_fks = options.ignore_foreign_keys.unwrap_or(false);
+    let stop_on_error = options.stop_on_error.unwrap_or(false);
+    if ignore_fks {
+        conn.query_drop("SET FOREIGN_KEY_CHECKS = 0").await.map_err(|e| e.to_string())?;
+    }
+
+    let start = std::time::Instant::now();
+    let total = statements.len();
+    let mut results = Vec::new();
+    for (index, (table, sql)) in statements.into_iter().enumerate() {
+        let table_start = std::time::Instant::now();
+        let outcome: Result<Vec<Vec<String>>, String> = async {
+            let mut result = conn.query_iter(sql).await.map_err(|e| e.to_string())?;
+            let rows: Vec<mysql_async::Row> = result.collect().await.map_err(|e| e.to_string())?;
+            Ok(rows
+                .into_iter()
+                .map(|row| (0..row.len()).map(|i| row.get::<Option<String>, _>(i).flatten().unwrap_or_else(|| "NULL".to_string())).collect())
+                .collect())
+        }
+        .await;
+
+        let result = match outcome {
+            Ok(rows) => {
+                // CHECK/REPAIR report problems as Msg_type = error rather than failing.
+                let error = rows.iter().find(|r| r.get(2).is_some_and(|t| t.eq_ignore_ascii_case("error"))).and_then(|r| r.get(3).cloned());
+                TableOperationResult { table, ok: error.is_none(), message: error, rows, duration_ms: table_start.elapsed().as_secs_f64() * 1000.0 }
+            }
+            Err(e) => TableOperationResult { table, ok: false, message: Some(e), rows: Vec::new(), duration_ms: table_start.elapsed().as_secs_f64() * 1000.0 },
+        };
+        let failed = !result.ok;
+        let _ = app_handle.emit(PROGRESS_EVENT, BulkProgress { operation: operation.to_string(), index, total, result: result.clone() });
+        results.push(result);
+        if failed && stop_on_error {
+            break;
+        }
+    }
+
+    if ignore_fks {
+        conn.query_drop("SET FOREIGN_KEY_CHECKS = 1").await.map_err(|e| e.to_string())?;
+    }
+
+    let succeeded = results.iter().filter(|r| r.ok).count();
+    Ok(BulkReport {
+        operation: operation.to_string(),
+        total,
+        succeeded,
+        failed: results.len() - succeeded,
+        skipped: total - results.len(),
+        results,
+        duration_ms: start.elapsed().as_secs_f64() * 1000.0,
+    })
+}
+
+/// Pairs each table with its statement, built from the qualified table name.
+fn per_table(db: &str, tables: Vec<String>, build: impl Fn(&str) -> String) -> Vec<(String, String)> {
+    tables
+        .into_iter()
+        .map(|t| {
+            let sql = build(&format!("{}.{}", quote_identifier(db), quote_identifier(&t)));
+            (t, sql)
+        })
+        .collect()
+}
+
+/// Orders the selection so tables referencing others in it come first, letting drops succeed
+/// without disabling foreign key checks. Cycles keep their original order.
+async fn drop_order(conn: &mut mysql_async::Conn, db: &str, tables: Vec<String>) -> Result<Vec<String>, String> {
+    let edges: Vec<(String, String)> = conn
+        .exec(
+            "SELECT TABLE_NAME, REFERENCED_TABLE_NAME FROM information_schema.REFERENTIAL_CONSTRAINTS
+             WHERE CONSTRAINT_SCHEMA = ? AND UNIQUE_CONSTRAINT_SCHEMA = ?",
+            (db, db),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut remaining = tables;
+    let mut ordered = Vec::new();
+    while !remaining.is_empty() {
+        // A table can go once no other remaining table references it.
+        let pos = remaining
+            .iter()
+            .position(|t| !edges.iter().any(|(child, parent)| parent == t && child != t && remaining.contains(child)))
+            .unwrap_or(0);
+        ordered.push(remaining.remove(pos));
+    }
+    Ok(ordered)
+}
+
+#[tauri::command]
+pub async fn drop_tables(db: String, tables: Vec<String>, options: Option<BulkOptions>, app_handle: AppHandle, state: State<'_, AppState>) -> Result<BulkReport, String> {
+    let options = options.unwrap_or_default();
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+    // Selections from the table list may include views, which need DROP VIEW.
+    let views: Vec<String> = conn
+        .exec("SELECT TABLE_NAME FROM information_schema.VIEWS WHERE TABLE_SCHEMA = ?", (&db,))
+        .await
+        .map_err(|e| e.to_string())?;
+    let tables = if options.ignore_foreign_keys.unwrap_or(false) {
+        tables
+    } else {
+        drop_order(&mut conn, &db, tables).await?
+    };
+    drop(conn);
+    let statements = tables
+        .into_iter()
+        .map(|t| {
+            let kind = if views.contains(&t) { "VIEW" } else { "TABLE" };
+            let sql = format!("DROP {} {}.{}", kind, quote_identifier(&db), quote_identifier(&t));
+            (t, sql)
+        })
+        .collect();
+    run_bulk(&app_handle, &state, "DROP", statements, options).await
+}
+
+#[tauri::command]
+pub async fn truncate_tables(db: String, tables: Vec<String>, options: Option<BulkOptions>, app_handle: AppHandle, state: State<'_, AppState>) -> Result<BulkReport, String> {
+    let statements = per_table(&db, tables, |t| format!("TRUNCATE TABLE {}", t));
+    run_bulk(&app_handle, &state, "TRUNCATE", statements, options.unwrap_or_default()).await
+}
+
+/// ANALYZE/OPTIMIZE/CHECK/REPAIR (as `table_maintenance`) run table by table.
+#[tauri::command]
+pub async fn maintain_tables(db: String, tables: Vec<String>, op: String, options: Option<BulkOptions>, app_handle: AppHandle, state: State<'_, AppState>) -> Result<BulkReport, String> {
+    let op_upper = op.to_uppercase();
+    if !["ANALYZE", "OPTIMIZE", "CHECK", "CHECKSUM", "REPAIR", "FLUSH"].contains(&op_upper.as_str()) {
+        return Err("Invalid maintenance operation".to_string());
+    }
+    let statements = per_table(&db, tables, |t| format!("{} TABLE {}", op_upper, t));
+    run_bulk(&app_handle, &state, &op_upper, statements, options.unwrap_or_default()).await
+}
+
+#[tauri::command]
+pub async fn change_tables_engine(db: String, tables: Vec<String>, engine: String, options: Option<BulkOptions>, app_handle: AppHandle, state: State<'_, AppState>) -> Result<BulkReport, String> {
+    check_word("engine", &engine)?;
+    let statements = per_table(&db, tables, |t| format!("ALTER TABLE {} ENGINE = {}", t, engine));
+    run_bulk(&app_handle, &state, "ENGINE", statements, options.unwrap_or_default()).await
+}
+
+/// Changes the default collation of each table; with `convert`, existing text columns are
+/// converted too (`CONVERT TO CHARACTER SET`).
+#[tauri::command]
+pub async fn change_tables_collation(db: String, tables: Vec<String>, collation: String, convert: Option<bool>, options: Option<BulkOptions>, app_handle: AppHandle, state: State<'_, AppState>) -> Result<BulkReport, String> {
+    check_word("collation", &collation)?;
+    let charset: Option<String> = {
+        let pool = {
+            let pool_guard = state.pool.lock().unwrap();
+            pool_guard.as_ref().cloned().ok_or("Not connected")?
+        };
+        let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+        conn.exec_first("SELECT CHARACTER_SET_NAME FROM information_schema.COLLATIONS WHERE COLLATION_NAME = ?", (&collation,))
+            .await
+            .map_err(|e| e.to_string())?
+    };
+    let charset = charset.ok_or(format!("Unknown collation: {}", collation))?;
+    let clause = if convert.unwrap_or(false) { "CONVERT TO CHARACTER SET" } else { "DEFAULT CHARACTER SET" };
+    let statements = per_table(&db, tables, |t| format!("ALTER TABLE {} {} {} COLLATE {}", t, clause, charset, collation));
+    run_bulk(&app_handle, &state, "COLLATION", statements, options.unwrap_or_default()).await
+}
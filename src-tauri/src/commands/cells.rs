@@ -0,0 +1,337 @@
+use tauri::State;
+use crate::state::AppState;
+use crate::commands::common::quote_identifier;
+use crate::commands::rows::{duplicate_warning, identity_condition, load_row_key, Condition};
+use crate::commands::values::load_column_meta;
+use base64::Engine;
+use mysql_async::prelude::*;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::Write;
+
+const DEFAULT_RANGE_BYTES: u64 = 64 * 1024;
+const FILE_CHUNK_BYTES: u64 = 1024 * 1024;
+
+#[derive(Serialize, Default)]
+pub struct ContentInfo {
+    pub kind: String,               // "text", "json", "xml", "image", "gzip", "zip", "pdf" or "binary"
+    pub mime: String,
+    pub encoding: Option<String>,   // for text: "utf-8", "utf-16le", "utf-16be" or "latin1"
+}
+
+#[derive(Serialize)]
+pub struct CellValue {
+    pub is_null: bool,
+    pub total_bytes: u64,
+    pub offset: u64,
+    pub length: u64,
+    pub complete: bool, // the returned range covers the whole value
+    pub content: ContentInfo,
+    pub text: Option<String>,   // decoded text when the value is textual
+    pub hex: Option<String>,    // hex dump when the value is binary
+    pub base64: Option<String>, // raw bytes for images, so the viewer can render them
+}
+
+/// Guesses the content type from magic bytes, falling back to text detection.
+pub fn detect_content(bytes: &[u8]) -> ContentInfo {
+    let info = |kind: &str, mime: &str| ContentInfo { kind: kind.to_string(), mime: mime.to_string(), encoding: None };
+    let magic: &[(&[u8], &str, &str)] = &[
+        (b"\x89PNG\r\n\x1a\n", "image", "image/png"),
+        (b"\xff\xd8\xff", "image", "image/jpeg"),
+        (b"GIF87a", "image", "image/gif"),
+        (b"GIF89a", "image", "image/gif"),
+        (b"BM", "image", "image/bmp"),
+        (b"\x1f\x8b", "gzip", "application/gzip"),
+        (b"PK\x03\x04", "zip", "application/zip"),
+        (b"%PDF-", "pdf", "application/pdf"),
+    ];
+    for (prefix, kind, mime) in magic {
+        if bytes.starts_with(prefix) {
+            return info(kind, mime);
+        }
+    }
+    if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        return info("image", "image/webp");
+    }
+
+    if let Some(encoding) = text_encoding(bytes) {
+        let text = decode_text(bytes, encoding);
+        let trimmed = text.trim_start_matches('\u{feff}').trim();
+        let mut content = if (trimmed.starts_with('{') || trimmed.starts_with('['))
+            && serde_json::from_str::<serde_json::Value>(trimmed).is_ok()
+        {
+            info("json", "application/json")
+        } else if trimmed.starts_with("<svg") || (trimmed.starts_with("<?xml") && trimmed.contains("<svg")) {
+            info("image", "image/svg+xml")
+        } else if trimmed.starts_with("<?xml") || (trimmed.starts_with('<') && trimmed.ends_with('>')) {
+            info("xml", "application/xml")
+        } else {
+            info("text", "text/plain")
+        };
+        content.encoding = Some(encoding.to_string());
+        return content;
+    }
+    info("binary", "application/octet-stream")
+}
+
+fn text_encoding(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(b"\xff\xfe") {
+        return Some("utf-16le");
+    }
+    if bytes.starts_with(b"\xfe\xff") {
+        return Some("utf-16be");
+    }
+    match std::str::from_utf8(bytes) {
+        Ok(s) if !s.chars().any(|c| c.is_control() && !c.is_whitespace()) => return Some("utf-8"),
+        Ok(_) => return None,
+        // A range may cut a multi-byte character at the end.
+        Err(e) if e.error_len().is_none() && bytes.len() - e.valid_up_to() < 4 => return Some("utf-8"),
+        Err(_) => {}
+    }
+    // Single-byte text: no NULs and mostly printable.
+    let printable = bytes.iter().filter(|&&b| b >= 0x20 || b == b'\n' || b == b'\r' || b == b'\t').count();
+    if !bytes.contains(&0) && printable * 100 >= bytes.len() * 95 {
+        Some("latin1")
+    } else {
+        None
+    }
+}
+
+fn decode_text(bytes: &[u8], encoding: &str) -> String {
+    match encoding {
+        "utf-16le" | "utf-16be" => {
+            let body = if bytes.starts_with(b"\xff\xfe") || bytes.starts_with(b"\xfe\xff") { &bytes[2..] } else { bytes };
+            let units: Vec<u16> = body
+                .chunks_exact(2)
+                .map(|c| if encoding == "utf-16le" { u16::from_le_bytes([c[0], c[1]]) } else { u16::from_be_bytes([c[0], c[1]]) })
+                .collect();
+            String::from_utf16_lossy(&units)
+        }
+        "latin1" => bytes.iter().map(|&b| b as char).collect(),
+        _ => String::from_utf8_lossy(bytes).into_owned(),
+    }
+}
+
+/// Classic 16-bytes-per-line hex dump, with offsets relative to the start of the value.
+pub fn hex_dump(bytes: &[u8], base_offset: u64) -> String {
+    let mut out = String::new();
+    for (i, chunk) in bytes.chunks(16).enumerate() {
+        let hex: Vec<String> = chunk.iter().map(|b| format!("{:02x}", b)).collect();
+        let ascii: String = chunk.iter().map(|&b| if (0x20..0x7f).contains(&b) { b as char } else { '.' }).collect();
+        out.push_str(&format!("{:08x}  {:<47}  |{}|\n", base_offset + (i * 16) as u64, hex.join(" "), ascii));
+    }
+    out
+}
+
+async fn cell_condition(
+    conn: &mut mysql_async::Conn,
+    db: &str,
+    table: &str,
+    row: &HashMap<String, serde_json::Value>,
+) -> Result<(Condition, Option<String>, &'static str), String> {
+    let key = load_row_key(conn, db, table).await?;
+    let condition = identity_condition(&key, row)?;
+    let warning = duplicate_warning(conn, db, table, &key, &condition).await?;
+    let limit = if key.kind == "none" { " LIMIT 1" } else { "" };
+    Ok((condition, warning, limit))
+}
+
+/// Reads `length` bytes starting at `offset` (0-based) of a cell; `None` when the row is gone.
+async fn read_range(
+    conn: &mut mysql_async::Conn,
+    db: &str,
+    table: &str,
+    column: &str,
+    condition: &Condition,
+    offset: u64,
+    length: u64,
+) -> Result<Option<(Option<u64>, Option<Vec<u8>>)>, String> {
+    let col = quote_identifier(column);
+    let sql = format!(
+        "SELECT OCTET_LENGTH({col}), SUBSTRING(CAST({col} AS BINARY), ?, ?) FROM `{db}`.`{table}` WHERE {cond} LIMIT 1",
+        col = col, db = db, table = table, cond = condition.sql
+    );
+    let mut params = vec![mysql_async::Value::UInt(offset + 1), mysql_async::Value::UInt(length)];
+    params.extend(condition.params.clone());
+    let row: Option<mysql_async::Row> = conn.exec_first(sql, params).await.map_err(|e| e.to_string())?;
+    Ok(row.map(|r| {
+        let total: Option<u64> = r.get(0).flatten();
+        let bytes = match r.get::<mysql_async::Value, _>(1) {
+            Some(mysql_async::Value::Bytes(b)) => Some(b),
+            _ => None,
+        };
+        (total, bytes)
+    }))
+}
+
+/// Fetches a cell in full or by byte range, identified by its row as in `update_cell`.
+/// Large values should be read in ranges; `length` defaults to 64 KiB.
+#[tauri::command]
+pub async fn get_cell_value(
+    db: String,
+    table: String,
+    column: String,
+    row: HashMap<String, serde_json::Value>,
+    offset: Option<u64>,
+    length: Option<u64>,
+    state: State<'_, AppState>,
+) -> Result<CellValue, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let (condition, _, _) = cell_condition(&mut conn, &db, &table, &row).await?;
+    let offset = offset.unwrap_or(0);
+    let length = length.unwrap_or(DEFAULT_RANGE_BYTES);
+    let (total, bytes) = read_range(&mut conn, &db, &table, &column, &condition, offset, length)
+        .await?
+        .ok_or("Row not found; it may have been changed or deleted by someone else")?;
+
+    let Some(total_bytes) = total else {
+        return Ok(CellValue {
+            is_null: true,
+            total_bytes: 0,
+            offset: 0,
+            length: 0,
+            complete: true,
+            content: ContentInfo::default(),
+            text: None,
+            hex: None,
+            base64: None,
+        });
+    };
+    let bytes = bytes.unwrap_or_default();
+
+    // Detect on the start of the value, whatever range was requested.
+    let content = if offset == 0 {
+        detect_content(&bytes)
+    } else {
+        let head = read_range(&mut conn, &db, &table, &column, &condition, 0, 512).await?;
+        detect_content(&head.and_then(|(_, b)| b).unwrap_or_default())
+    };
+
+    let (text, hex) = match &content.encoding {
+        Some(encoding) => (Some(decode_text(&bytes, encoding)), None),
+        None => (None, Some(hex_dump(&bytes, offset))),
+    };
+    let base64 = (content.kind == "image").then(|| base64::engine::general_purpose::STANDARD.encode(&bytes));
+
+    Ok(CellValue {
+        is_null: false,
+        total_bytes,
+        offset,
+        length: bytes.len() as u64,
+        complete: offset == 0 && bytes.len() as u64 >= total_bytes,
+        content,
+        text,
+        hex,
+        base64,
+    })
+}
+
+/// Writes a cell's raw bytes to a file, reading the value in chunks. Returns the bytes written.
+#[tauri::command]
+pub async fn save_cell_to_file(
+    db: String,
+    table: String,
+    column: String,
+    row: HashMap<String, serde_json::Value>,
+    file_path: String,
+    state: State<'_, AppState>,
+) -> Result<u64, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let (condition, _, _) = cell_condition(&mut conn, &db, &table, &row).await?;
+    let mut file = std::io::BufWriter::new(std::fs::File::create(&file_path).map_err(|e| e.to_string())?);
+    let mut written = 0u64;
+    loop {
+        let (total, bytes) = read_range(&mut conn, &db, &table, &column, &condition, written, FILE_CHUNK_BYTES)
+            .await?
+            .ok_or("Row not found; it may have been changed or deleted by someone else")?;
+        let total = total.ok_or("Cell is NULL")?;
+        let bytes = bytes.unwrap_or_default();
+        file.write_all(&bytes).map_err(|e| e.to_string())?;
+        written += bytes.len() as u64;
+        if bytes.is_empty() || written >= total {
+            break;
+        }
+    }
+    file.flush().map_err(|e| e.to_string())?;
+    Ok(written)
+}
+
+#[derive(Serialize)]
+pub struct CellLoadResult {
+    pub bytes: u64,
+    pub affected_rows: u64,
+    pub warning: Option<String>,
+}
+
+/// Replaces a BLOB/TEXT/BINARY/CHAR cell with the contents of a file. Text columns require
+/// UTF-8 content; the size is checked against the column and `max_allowed_packet`.
+#[tauri::command]
+pub async fn load_file_into_cell(
+    db: String,
+    table: String,
+    column: String,
+    row: HashMap<String, serde_json::Value>,
+    file_path: String,
+    state: State<'_, AppState>,
+) -> Result<CellLoadResult, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let columns = load_column_meta(&mut conn, &db, &table).await?;
+    let col = columns.iter().find(|c| c.name == column).ok_or(format!("Unknown column `{}`", column))?;
+    let is_binary = matches!(col.data_type.as_str(), "binary" | "varbinary" | "tinyblob" | "blob" | "mediumblob" | "longblob");
+    let is_text = matches!(col.data_type.as_str(), "char" | "varchar" | "tinytext" | "text" | "mediumtext" | "longtext" | "json");
+    if !is_binary && !is_text {
+        return Err(format!("Column `{}` ({}) cannot hold file contents", column, col.column_type));
+    }
+    if col.is_generated() {
+        return Err(format!("Column `{}` is generated", column));
+    }
+
+    let bytes = std::fs::read(&file_path).map_err(|e| e.to_string())?;
+    if is_text {
+        let text = std::str::from_utf8(&bytes).map_err(|_| format!("File is not valid UTF-8 text; `{}` is a text column", column))?;
+        if let Some(max) = col.char_max_length {
+            if text.chars().count() as u64 > max {
+                return Err(format!("File has {} characters but `{}` holds at most {}", text.chars().count(), column, max));
+            }
+        }
+    }
+    if let Some(max) = col.octet_length.filter(|_| is_binary) {
+        if bytes.len() as u64 > max {
+            return Err(format!("File is {} bytes but `{}` holds at most {}", bytes.len(), column, max));
+        }
+    }
+    let max_packet: Option<u64> = conn.query_first("SELECT @@max_allowed_packet").await.map_err(|e| e.to_string())?;
+    if let Some(max) = max_packet {
+        if bytes.len() as u64 >= max {
+            return Err(format!("File is {} bytes, larger than the server's max_allowed_packet ({})", bytes.len(), max));
+        }
+    }
+
+    let (condition, warning, limit) = cell_condition(&mut conn, &db, &table, &row).await?;
+    let size = bytes.len() as u64;
+    let mut params = vec![mysql_async::Value::Bytes(bytes)];
+    params.extend(condition.params);
+    conn.exec_drop(
+        format!("UPDATE `{}`.`{}` SET {} = ? WHERE {}{}", db, table, quote_identifier(&column), condition.sql, limit),
+        params,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(CellLoadResult { bytes: size, affected_rows: conn.affected_rows(), warning })
+}
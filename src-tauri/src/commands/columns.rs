@@ -0,0 +1,303 @@
+use tauri::State;
+use crate::state::AppState;
+use crate::commands::common::{quote_identifier, SqlMode};
+use crate::commands::values::parse_members;
+use mysql_async::prelude::*;
+use serde::{Deserialize, Serialize};
+
+const COLUMN_TYPES: &[&str] = &[
+    "TINYINT", "SMALLINT", "MEDIUMINT", "INT", "INTEGER", "BIGINT", "DECIMAL", "NUMERIC", "FLOAT", "DOUBLE", "REAL", "BIT", "BOOLEAN", "BOOL",
+    "DATE", "DATETIME", "TIMESTAMP", "TIME", "YEAR",
+    "CHAR", "VARCHAR", "BINARY", "VARBINARY", "TINYTEXT", "TEXT", "MEDIUMTEXT", "LONGTEXT", "TINYBLOB", "BLOB", "MEDIUMBLOB", "LONGBLOB",
+    "ENUM", "SET", "JSON", "UUID", "INET4", "INET6",
+    "GEOMETRY", "POINT", "LINESTRING", "POLYGON", "MULTIPOINT", "MULTILINESTRING", "MULTIPOLYGON", "GEOMETRYCOLLECTION",
+];
+
+/// Structured column definition, shared by the ALTER and CREATE TABLE builders.
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct ColumnDefinition {
+    pub name: String,
+    pub data_type: String,       // e.g. "VARCHAR", "DECIMAL", "ENUM"
+    pub length: Option<String>,  // "255", "10,2" or fractional seconds precision
+    #[serde(default)]
+    pub values: Vec<String>,     // ENUM / SET members
+    #[serde(default)]
+    pub unsigned: bool,
+    #[serde(default)]
+    pub zerofill: bool,
+    #[serde(default = "default_true")]
+    pub nullable: bool,
+    pub default_kind: Option<String>, // "none", "null", "value", "current_timestamp" or "expression"
+    pub default_value: Option<String>,
+    pub on_update_current_timestamp: Option<bool>,
+    #[serde(default)]
+    pub auto_increment: bool,
+    pub charset: Option<String>,
+    pub collation: Option<String>,
+    pub comment: Option<String>,
+    pub generated_expression: Option<String>,
+    pub generated_stored: Option<bool>, // STORED vs VIRTUAL
+    #[serde(default)]
+    pub first: bool,
+    pub after: Option<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Deserialize)]
+pub struct ColumnChange {
+    pub action: String,              // "add", "modify", "rename" or "drop"
+    pub column: Option<String>,      // existing column for modify/rename/drop
+    pub definition: Option<ColumnDefinition>,
+    pub new_name: Option<String>,    // for "rename"
+}
+
+pub fn check_word(kind: &str, value: &str) -> Result<(), String> {
+    if value.is_empty() || !value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(format!("Invalid {}: {}", kind, value));
+    }
+    Ok(())
+}
+
+/// Column type with length/members and attributes, e.g. `DECIMAL(10,2) UNSIGNED`.
+pub fn column_type_sql(def: &ColumnDefinition, mode: SqlMode) -> Result<String, String> {
+    let base = def.data_type.trim().to_uppercase();
+    if !COLUMN_TYPES.contains(&base.as_str()) {
+        return Err(format!("Unknown column type: {}", def.data_type));
+    }
+    let mut sql = base.clone();
+    if base == "ENUM" || base == "SET" {
+        if def.values.is_empty() {
+            return Err(format!("`{}`: {} needs at least one value", def.name, base));
+        }
+        let members: Vec<String> = def.values.iter().map(|v| mode.quote(v)).collect();
+        sql.push_str(&format!("({})", members.join(",")));
+    } else if let Some(length) = def.length.as_deref().map(str::trim).filter(|l| !l.is_empty()) {
+        if !length.chars().all(|c| c.is_ascii_digit() || c == ',' || c == ' ') {
+            return Err(format!("`{}`: invalid length {}", def.name, length));
+        }
+        sql.push_str(&format!("({})", length.replace(' ', "")));
+    } else if base == "VARCHAR" || base == "VARBINARY" {
+        return Err(format!("`{}`: {} requires a length", def.name, base));
+    }
+    if def.unsigned {
+        sql.push_str(" UNSIGNED");
+    }
+    if def.zerofill {
+        sql.push_str(" ZEROFILL");
+    }
+    Ok(sql)
+}
+
+/// `CURRENT_TIMESTAMP` with the column's fractional seconds precision, which the server
+/// requires to match the type, e.g. `DATETIME(3) DEFAULT CURRENT_TIMESTAMP(3)`.
+fn current_timestamp_sql(def: &ColumnDefinition) -> String {
+    match def.length.as_deref().map(str::trim).filter(|l| !l.is_empty() && l.chars().all(|c| c.is_ascii_digit())) {
+        Some(fsp) => format!("CURRENT_TIMESTAMP({})", fsp),
+        None => "CURRENT_TIMESTAMP".to_string(),
+    }
+}
+
+/// Full column definition as used after the column name in ADD/MODIFY/CHANGE/CREATE.
+pub fn column_definition_sql(def: &ColumnDefinition, mode: SqlMode) -> Result<String, String> {
+    if def.name.trim().is_empty() {
+        return Err("Column name is required".to_string());
+    }
+    let mut sql = format!("{} {}", quote_identifier(&def.name), column_type_sql(def, mode)?);
+
+    if let Some(charset) = def.charset.as_deref().filter(|c| !c.is_empty()) {
+        check_word("character set", charset)?;
+        sql.push_str(&format!(" CHARACTER SET {}", charset));
+    }
+    if let Some(collation) = def.collation.as_deref().filter(|c| !c.is_empty()) {
+        check_word("collation", collation)?;
+        sql.push_str(&format!(" COLLATE {}", collation));
+    }
+
+    if let Some(expr) = def.generated_expression.as_deref().map(str::trim).filter(|e| !e.is_empty()) {
+        // Generated columns take neither DEFAULT nor AUTO_INCREMENT.
+        let kind = if def.generated_stored.unwrap_or(false) { "STORED" } else { "VIRTUAL" };
+        sql.push_str(&format!(" GENERATED ALWAYS AS ({}) {}", expr, kind));
+        sql.push_str(if def.nullable { " NULL" } else { " NOT NULL" });
+    } else {
+        sql.push_str(if def.nullable { " NULL" } else { " NOT NULL" });
+        match def.default_kind.as_deref().unwrap_or("none") {
+            "none" => {}
+            "null" => {
+                if !def.nullable {
+                    return Err(format!("`{}`: DEFAULT NULL on a NOT NULL column", def.name));
+                }
+                sql.push_str(" DEFAULT NULL");
+            }
+            "value" => {
+                let value = def.default_value.clone().unwrap_or_default();
+                sql.push_str(&format!(" DEFAULT {}", mode.quote(&value)));
+            }
+            "current_timestamp" => sql.push_str(&format!(" DEFAULT {}", current_timestamp_sql(def))),
+            "expression" => {
+                let expr = def.default_value.as_deref().map(str::trim).filter(|e| !e.is_empty())
+                    .ok_or(format!("`{}`: default expression is empty", def.name))?;
+                sql.push_str(&format!(" DEFAULT ({})", expr));
+            }
+            other => return Err(format!("`{}`: unknown default kind {}", def.name, other)),
+        }
+        if def.on_update_current_timestamp.unwrap_or(false) {
+            sql.push_str(&format!(" ON UPDATE {}", current_timestamp_sql(def)));
+        }
+        if def.auto_increment {
+            sql.push_str(" AUTO_INCREMENT");
+        }
+    }
+
+    if let Some(comment) = def.comment.as_deref().filter(|c| !c.is_empty()) {
+        sql.push_str(&format!(" COMMENT {}", mode.quote(comment)));
+    }
+    Ok(sql)
+}
+
+fn position_sql(def: &ColumnDefinition) -> String {
+    if def.first {
+        " FIRST".to_string()
+    } else if let Some(after) = def.after.as_deref().filter(|a| !a.is_empty()) {
+        format!(" AFTER {}", quote_identifier(after))
+    } else {
+        String::new()
+    }
+}
+
+/// Builds one `ALTER TABLE` applying every change in order.
+pub fn alter_columns_sql(db: &str, table: &str, changes: &[ColumnChange], mode: SqlMode) -> Result<String, String> {
+    if changes.is_empty() {
+        return Err("No column changes given".to_string());
+    }
+    let mut clauses = Vec::new();
+    for (i, change) in changes.iter().enumerate() {
+        let existing = || change.column.as_deref().filter(|c| !c.is_empty()).ok_or(format!("Change {}: column is required", i + 1));
+        let definition = || change.definition.as_ref().ok_or(format!("Change {}: definition is required", i + 1));
+        let clause = match change.action.to_lowercase().as_str() {
+            "add" => {
+                let def = definition()?;
+                format!("ADD COLUMN {}{}", column_definition_sql(def, mode)?, position_sql(def))
+            }
+            "modify" => {
+                let old = existing()?;
+                let def = definition()?;
+                if def.name.is_empty() || def.name == old {
+                    let def = ColumnDefinition { name: old.to_string(), ..def.clone() };
+                    format!("MODIFY COLUMN {}{}", column_definition_sql(&def, mode)?, position_sql(&def))
+                } else {
+                    format!("CHANGE COLUMN {} {}{}", quote_identifier(old), column_definition_sql(def, mode)?, position_sql(def))
+                }
+            }
+            "rename" => {
+                let new_name = change.new_name.as_deref().filter(|n| !n.is_empty()).ok_or(format!("Change {}: new name is required", i + 1))?;
+                format!("RENAME COLUMN {} TO {}", quote_identifier(existing()?), quote_identifier(new_name))
+            }
+            "drop" => format!("DROP COLUMN {}", quote_identifier(existing()?)),
+            other => return Err(format!("Change {}: unknown action {}", i + 1, other)),
+        };
+        clauses.push(clause);
+    }
+    Ok(format!("ALTER TABLE {}.{}\n  {}", quote_identifier(db), quote_identifier(table), clauses.join(",\n  ")))
+}
+
+/// Returns the ALTER statement `alter_columns` would run, without touching the server.
+#[tauri::command]
+pub async fn preview_alter_columns(db: String, table: String, changes: Vec<ColumnChange>, state: State<'_, AppState>) -> Result<String, String> {
+    let pool = state.pool.lock().unwrap().as_ref().cloned();
+    alter_columns_sql(&db, &table, &changes, SqlMode::of_pool(pool).await?)
+}
+
+/// Applies a batch of column changes as a single `ALTER TABLE` and returns the SQL that ran.
+#[tauri::command]
+pub async fn alter_columns(db: String, table: String, changes: Vec<ColumnChange>, state: State<'_, AppState>) -> Result<String, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+    let sql = alter_columns_sql(&db, &table, &changes, SqlMode::load(&mut conn).await?)?;
+    conn.query_drop(&sql).await.map_err(|e| e.to_string())?;
+    Ok(sql)
+}
+
+/// Reads a column back into a `ColumnDefinition`, to pre-fill the edit form.
+#[tauri::command]
+pub async fn get_column_definition(db: String, table: String, column: String, state: State<'_, AppState>) -> Result<ColumnDefinition, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let row: Option<mysql_async::Row> = conn
+        .exec_first(
+            "SELECT DATA_TYPE, COLUMN_TYPE, IS_NULLABLE, COLUMN_DEFAULT, EXTRA, CHARACTER_SET_NAME, COLLATION_NAME,
+                    COLUMN_COMMENT, GENERATION_EXPRESSION, ORDINAL_POSITION
+             FROM information_schema.COLUMNS WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ? AND COLUMN_NAME = ?",
+            (&db, &table, &column),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    let row = row.ok_or(format!("Column `{}` not found in `{}`.`{}`", column, db, table))?;
+
+    let text = |i: usize| row.get::<Option<String>, _>(i).flatten();
+    let data_type = text(0).unwrap_or_default().to_uppercase();
+    let column_type = text(1).unwrap_or_default();
+    let extra = text(4).unwrap_or_default().to_uppercase();
+    let default = text(3);
+    let generation = text(8).filter(|g| !g.is_empty());
+    let position: u64 = row.get::<Option<u64>, _>(9).flatten().unwrap_or(1);
+
+    // Length/members sit in the parentheses of COLUMN_TYPE.
+    let inner = match (column_type.find('('), column_type.rfind(')')) {
+        (Some(open), Some(close)) if close > open => Some(column_type[open + 1..close].to_string()),
+        _ => None,
+    };
+    let values = if data_type == "ENUM" || data_type == "SET" { parse_members(&column_type) } else { Vec::new() };
+
+    // MySQL flags expression defaults with DEFAULT_GENERATED; MariaDB quotes literal defaults.
+    let (default_kind, default_value) = match default {
+        None if text(2).as_deref() == Some("YES") && generation.is_none() => ("null", None),
+        None => ("none", None),
+        Some(d) if d == "NULL" => ("null", None),
+        Some(d) if d.to_lowercase().starts_with("current_timestamp") => ("current_timestamp", None),
+        Some(d) if extra.contains("DEFAULT_GENERATED") => ("expression", Some(d)),
+        Some(d) if d.len() >= 2 && d.starts_with('\'') && d.ends_with('\'') => ("value", Some(d[1..d.len() - 1].replace("''", "'"))),
+        Some(d) => ("value", Some(d)),
+    };
+
+    let after: Option<String> = if position > 1 {
+        conn.exec_first(
+            "SELECT COLUMN_NAME FROM information_schema.COLUMNS WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ? AND ORDINAL_POSITION = ?",
+            (&db, &table, position - 1),
+        )
+        .await
+        .map_err(|e| e.to_string())?
+    } else {
+        None
+    };
+
+    Ok(ColumnDefinition {
+        name: column,
+        length: if values.is_empty() { inner } else { None },
+        values,
+        unsigned: column_type.to_lowercase().contains("unsigned"),
+        zerofill: column_type.to_lowercase().contains("zerofill"),
+        nullable: text(2).as_deref() == Some("YES"),
+        default_kind: Some(default_kind.to_string()),
+        default_value,
+        on_update_current_timestamp: Some(extra.contains("ON UPDATE CURRENT_TIMESTAMP")),
+        auto_increment: extra.contains("AUTO_INCREMENT"),
+        charset: text(5),
+        collation: text(6),
+        comment: text(7).filter(|c| !c.is_empty()),
+        generated_stored: generation.as_ref().map(|_| extra.contains("STORED") || extra.contains("PERSISTENT")),
+        generated_expression: generation,
+        first: position == 1,
+        after,
+        data_type,
+    })
+}
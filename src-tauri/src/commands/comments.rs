@@ -0,0 +1,141 @@
+use tauri::State;
+use crate::state::AppState;
+use crate::commands::common::{quote_identifier, SqlMode};
+use crate::commands::sql_lexer::{tokenize, TokenKind};
+use mysql_async::prelude::*;
+
+/// Sets (or replaces) the top-level `COMMENT '...'` of a column or index definition.
+pub fn with_comment(definition: &str, comment: &str, mode: SqlMode) -> String {
+    let literal = format!("COMMENT {}", mode.quote(comment));
+    let tokens: Vec<_> = tokenize(definition).into_iter().filter(|t| t.kind != TokenKind::Whitespace).collect();
+    let mut depth = 0;
+    for (i, tok) in tokens.iter().enumerate() {
+        match tok.kind {
+            TokenKind::OpenParen => depth += 1,
+            TokenKind::CloseParen => depth -= 1,
+            _ if depth == 0 && tok.is_word("COMMENT") => {
+                let value = if tokens.get(i + 1).is_some_and(|t| t.text == "=") { i + 2 } else { i + 1 };
+                if let Some(v) = tokens.get(value).filter(|t| t.kind == TokenKind::String) {
+                    let end = v.start + v.text.len();
+                    return format!("{}{}{}", &definition[..tok.start], literal, &definition[end..]);
+                }
+            }
+            _ => {}
+        }
+    }
+    format!("{} {}", definition.trim_end(), literal)
+}
+
+async fn show_create_table(conn: &mut mysql_async::Conn, db: &str, table: &str) -> Result<String, String> {
+    let row: Option<(String, String)> = conn
+        .query_first(format!("SHOW CREATE TABLE {}.{}", quote_identifier(db), quote_identifier(table)))
+        .await
+        .map_err(|e| e.to_string())?;
+    row.map(|(_, create)| create).ok_or(format!("Table `{}`.`{}` not found", db, table))
+}
+
+/// Definition lines between the parentheses of `SHOW CREATE TABLE`, without trailing commas.
+fn definition_lines(create: &str) -> Vec<&str> {
+    create
+        .lines()
+        .skip(1)
+        .filter(|l| l.starts_with("  "))
+        .map(|l| l.trim().trim_end_matches(','))
+        .collect()
+}
+
+#[tauri::command]
+pub async fn set_table_comment(db: String, table: String, comment: String, state: State<'_, AppState>) -> Result<(), String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+    let mode = SqlMode::load(&mut conn).await?;
+    conn.query_drop(format!(
+        "ALTER TABLE {}.{} COMMENT = {}",
+        quote_identifier(&db), quote_identifier(&table), mode.quote(&comment)
+    ))
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// Changes a column comment, keeping the rest of its definition exactly as the server reports it.
+#[tauri::command]
+pub async fn set_column_comment(db: String, table: String, column: String, comment: String, state: State<'_, AppState>) -> Result<String, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let create = show_create_table(&mut conn, &db, &table).await?;
+    let mode = SqlMode::load(&mut conn).await?;
+    let prefix = format!("{} ", quote_identifier(&column));
+    let definition = definition_lines(&create)
+        .into_iter()
+        .find(|l| l.starts_with(&prefix))
+        .ok_or(format!("Column `{}` not found", column))?;
+
+    let sql = format!(
+        "ALTER TABLE {}.{} MODIFY COLUMN {}",
+        quote_identifier(&db), quote_identifier(&table), with_comment(definition, &comment, mode)
+    );
+    conn.query_drop(&sql).await.map_err(|e| e.to_string())?;
+    Ok(sql)
+}
+
+/// Index comments can only be changed by recreating the index; both steps run in one ALTER.
+#[tauri::command]
+pub async fn set_index_comment(db: String, table: String, index: String, comment: String, state: State<'_, AppState>) -> Result<String, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let create = show_create_table(&mut conn, &db, &table).await?;
+    let mode = SqlMode::load(&mut conn).await?;
+    let is_primary = index == "PRIMARY";
+    let name = quote_identifier(&index);
+    let definition = definition_lines(&create)
+        .into_iter()
+        .find(|l| {
+            if is_primary {
+                l.starts_with("PRIMARY KEY")
+            } else {
+                ["KEY ", "UNIQUE KEY ", "FULLTEXT KEY ", "SPATIAL KEY "]
+                    .iter()
+                    .any(|kind| l.strip_prefix(kind).is_some_and(|rest| rest.starts_with(&name)))
+            }
+        })
+        .ok_or(format!("Index `{}` not found", index))?;
+
+    let drop = if is_primary { "DROP PRIMARY KEY".to_string() } else { format!("DROP INDEX {}", name) };
+    let sql = format!(
+        "ALTER TABLE {}.{} {}, ADD {}",
+        quote_identifier(&db), quote_identifier(&table), drop, with_comment(definition, &comment, mode)
+    );
+    conn.query_drop(&sql).await.map_err(|e| e.to_string())?;
+    Ok(sql)
+}
+
+#[tauri::command]
+pub async fn set_routine_comment(db: String, name: String, routine_type: String, comment: String, state: State<'_, AppState>) -> Result<(), String> {
+    let routine_type = routine_type.to_uppercase();
+    if routine_type != "PROCEDURE" && routine_type != "FUNCTION" {
+        return Err(format!("Invalid routine type: {}", routine_type));
+    }
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+    let mode = SqlMode::load(&mut conn).await?;
+    conn.query_drop(format!(
+        "ALTER {} {}.{} COMMENT {}",
+        routine_type, quote_identifier(&db), quote_identifier(&name), mode.quote(&comment)
+    ))
+    .await
+    .map_err(|e| e.to_string())
+}
@@ -1,14 +1,15 @@
 use mysql_async;
 use serde_json;
 
+/// Converts a MySQL value for the frontend. Non-UTF-8 bytes become `{"$hex": "..."}`,
+/// which `json_param` and the key cursors accept back.
 pub fn mysql_to_json(val: mysql_async::Value) -> serde_json::Value {
     match val {
         mysql_async::Value::NULL => serde_json::Value::Null,
-        mysql_async::Value::Bytes(b) => {
-            String::from_utf8(b)
-                .map(serde_json::Value::String)
-                .unwrap_or_else(|_| serde_json::Value::Null)
-        }
+        mysql_async::Value::Bytes(b) => match String::from_utf8(b) {
+            Ok(s) => serde_json::Value::String(s),
+            Err(e) => serde_json::json!({ "$hex": to_hex(e.as_bytes()) }),
+        },
         mysql_async::Value::Int(i) => serde_json::Value::Number(i.into()),
         mysql_async::Value::UInt(u) => serde_json::Value::Number(u.into()),
         mysql_async::Value::Float(f) => {
@@ -30,6 +31,32 @@ pub fn mysql_to_json(val: mysql_async::Value) -> serde_json::Value {
     }
 }
 
+/// Renders a value from a prepared statement the way the text protocol sends it, so the same
+/// query shows the same values whether or not it had parameters.
+pub fn text_protocol_value(val: mysql_async::Value, column: &mysql_async::Column) -> mysql_async::Value {
+    use mysql_async::consts::ColumnType;
+    let fraction = |us: u32| match column.decimals().min(6) as usize {
+        0 => String::new(),
+        n => format!(".{:06}", us)[..n + 1].to_string(),
+    };
+    let text = match val {
+        mysql_async::Value::NULL | mysql_async::Value::Bytes(_) => return val,
+        mysql_async::Value::Int(n) => n.to_string(),
+        mysql_async::Value::UInt(n) => n.to_string(),
+        mysql_async::Value::Float(n) => n.to_string(),
+        mysql_async::Value::Double(n) => n.to_string(),
+        mysql_async::Value::Date(y, m, d, h, i, s, us) => match column.column_type() {
+            ColumnType::MYSQL_TYPE_DATE | ColumnType::MYSQL_TYPE_NEWDATE => format!("{:04}-{:02}-{:02}", y, m, d),
+            _ => format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02}{}", y, m, d, h, i, s, fraction(us)),
+        },
+        mysql_async::Value::Time(neg, d, h, m, s, us) => {
+            let sign = if neg { "-" } else { "" };
+            format!("{}{:02}:{:02}:{:02}{}", sign, d * 24 + h as u32, m, s, fraction(us))
+        }
+    };
+    mysql_async::Value::Bytes(text.into_bytes())
+}
+
 pub fn render_pagination_html(page: u32, total: u64, limit: u32) -> String {
     let total_pages = (total as f64 / limit as f64).ceil() as u32;
     let total_pages = if total_pages == 0 { 1 } else { total_pages };
@@ -89,9 +116,17 @@ pub fn render_table_html(columns: &[String], rows: &[Vec<serde_json::Value>]) ->
             match val {
                 serde_json::Value::Null => body.push_str("<td class=\"text-white/30 italic\">NULL</td>"),
                 serde_json::Value::String(s) => {
-                    let display = if s.len() > 200 { format!("{}...", &s[..200]) } else { s.clone() };
+                    let display = if s.chars().count() > 200 { format!("{}...", s.chars().take(200).collect::<String>()) } else { s.clone() };
                     body.push_str(&format!("<td><div class=\"truncate max-w-[300px]\">{}</div></td>", display));
                 }
+                serde_json::Value::Object(o) if o.contains_key("$hex") => {
+                    let hex = o["$hex"].as_str().unwrap_or_default();
+                    let preview = if hex.len() > 32 { format!("0x{}...", &hex[..32]) } else { format!("0x{}", hex) };
+                    body.push_str(&format!(
+                        "<td><div class=\"truncate max-w-[300px] font-mono opacity-70\" title=\"{} bytes\">{}</div></td>",
+                        hex.len() / 2, preview
+                    ));
+                }
                 _ => body.push_str(&format!("<td>{}</td>", val)),
             }
         }
@@ -104,3 +139,107 @@ pub fn render_table_html(columns: &[String], rows: &[Vec<serde_json::Value>]) ->
 
     (head, body)
 }
+
+pub fn to_hex(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        out.push_str(&format!("{:02X}", b));
+    }
+    out
+}
+
+pub fn escape_sql_string(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\'', "\\'")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\x00', "\\0")
+        .replace('\x1a', "\\Z")
+}
+
+/// How string literals are written for a session. Under NO_BACKSLASH_ESCAPES a backslash
+/// is an ordinary character, so `\'` would end the literal early.
+#[derive(Clone, Copy, Default)]
+pub struct SqlMode {
+    pub no_backslash_escapes: bool,
+}
+
+impl SqlMode {
+    pub async fn load(conn: &mut mysql_async::Conn) -> Result<SqlMode, String> {
+        use mysql_async::prelude::Queryable;
+        let mode: Option<String> = conn.query_first("SELECT @@SESSION.sql_mode").await.map_err(|e| e.to_string())?;
+        Ok(SqlMode {
+            no_backslash_escapes: mode.is_some_and(|m| m.split(',').any(|f| f.trim().eq_ignore_ascii_case("NO_BACKSLASH_ESCAPES"))),
+        })
+    }
+
+    /// Mode of a fresh pooled connection; the server default when not connected, for previews.
+    pub async fn of_pool(pool: Option<mysql_async::Pool>) -> Result<SqlMode, String> {
+        match pool {
+            Some(pool) => SqlMode::load(&mut pool.get_conn().await.map_err(|e| e.to_string())?).await,
+            None => Ok(SqlMode::default()),
+        }
+    }
+
+    /// Quoted string literal, e.g. `'it\'s'`, or `'it''s'` under NO_BACKSLASH_ESCAPES.
+    pub fn quote(&self, s: &str) -> String {
+        if self.no_backslash_escapes {
+            format!("'{}'", s.replace('\'', "''"))
+        } else {
+            format!("'{}'", escape_sql_string(s))
+        }
+    }
+}
+
+/// Plain-text rendering of a value; `None` for NULL. Non-UTF-8 bytes are rendered as `0x...` hex.
+pub fn value_to_text(val: &mysql_async::Value) -> Option<String> {
+    match val {
+        mysql_async::Value::NULL => None,
+        mysql_async::Value::Bytes(b) => Some(match std::str::from_utf8(b) {
+            Ok(s) => s.to_string(),
+            Err(_) => format!("0x{}", to_hex(b)),
+        }),
+        mysql_async::Value::Int(n) => Some(n.to_string()),
+        mysql_async::Value::UInt(n) => Some(n.to_string()),
+        mysql_async::Value::Float(n) => Some(n.to_string()),
+        mysql_async::Value::Double(n) => Some(n.to_string()),
+        mysql_async::Value::Date(y, m, d, h, i, s, us) => Some(if *us > 0 {
+            format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:06}", y, m, d, h, i, s, us)
+        } else {
+            format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02}", y, m, d, h, i, s)
+        }),
+        mysql_async::Value::Time(neg, d, h, m, s, us) => {
+            let sign = if *neg { "-" } else { "" };
+            Some(format!("{}{}:{:02}:{:02}.{:06}", sign, d * 24 + *h as u32, m, s, us))
+        }
+    }
+}
+
+/// SQL literal for a value, suitable for INSERT statements.
+pub fn sql_literal(val: &mysql_async::Value) -> String {
+    match val {
+        mysql_async::Value::NULL => "NULL".to_string(),
+        mysql_async::Value::Bytes(b) => match std::str::from_utf8(b) {
+            Ok(s) => format!("'{}'", escape_sql_string(s)),
+            Err(_) => format!("0x{}", to_hex(b)),
+        },
+        mysql_async::Value::Int(_) | mysql_async::Value::UInt(_) | mysql_async::Value::Float(_) | mysql_async::Value::Double(_) => {
+            value_to_text(val).unwrap_or_default()
+        }
+        mysql_async::Value::Date(y, m, d, h, min, s, us) => {
+            format!("'{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:06}'", y, m, d, h, min, s, us)
+        }
+        _ => format!("'{}'", value_to_text(val).unwrap_or_default()),
+    }
+}
+
+pub fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+pub fn quote_identifier(name: &str) -> String {
+    format!("`{}`", name.replace('`', "``"))
+}
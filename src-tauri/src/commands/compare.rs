@@ -0,0 +1,282 @@
+use tauri::State;
+use crate::state::{AppState, DbConfig};
+use crate::commands::common::{mysql_to_json, value_to_text};
+use mysql_async::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+#[derive(Deserialize)]
+pub struct CompareSource {
+    pub sql: String,
+    pub db: Option<String>,
+    /// Run against a separate server instead of the current connection.
+    pub connection: Option<DbConfig>,
+}
+
+#[derive(Deserialize, Default)]
+pub struct CompareOptions {
+    pub key_columns: Option<Vec<String>>,
+    pub ignore_columns: Option<Vec<String>>,
+    pub max_rows: Option<usize>, // cap on returned rows per category, counts are always complete
+}
+
+#[derive(Serialize)]
+pub struct CellDiff {
+    pub column: String,
+    pub left: serde_json::Value,
+    pub right: serde_json::Value,
+}
+
+#[derive(Serialize)]
+pub struct ChangedRow {
+    pub key: Vec<serde_json::Value>,
+    pub left: Vec<serde_json::Value>,
+    pub right: Vec<serde_json::Value>,
+    pub diffs: Vec<CellDiff>,
+}
+
+#[derive(Serialize, Default)]
+pub struct CompareSummary {
+    pub left_rows: usize,
+    pub right_rows: usize,
+    pub unchanged: usize,
+    pub added: usize,
+    pub removed: usize,
+    pub changed: usize,
+    pub duplicate_keys_left: usize,
+    pub duplicate_keys_right: usize,
+}
+
+#[derive(Serialize)]
+pub struct CompareResult {
+    pub columns: Vec<String>,
+    pub left_only_columns: Vec<String>,
+    pub right_only_columns: Vec<String>,
+    pub key_columns: Vec<String>,
+    pub added: Vec<Vec<serde_json::Value>>,
+    pub removed: Vec<Vec<serde_json::Value>>,
+    pub changed: Vec<ChangedRow>,
+    pub summary: CompareSummary,
+    pub truncated: bool,
+    pub identical: bool,
+}
+
+struct FetchedSet {
+    columns: Vec<String>,
+    rows: Vec<Vec<mysql_async::Value>>,
+}
+
+async fn fetch_rows(conn: &mut mysql_async::Conn, source: &CompareSource) -> Result<FetchedSet, String> {
+    if let Some(db_name) = &source.db {
+        if !db_name.is_empty() {
+            conn.query_drop(format!("USE `{}`", db_name)).await.map_err(|e| e.to_string())?;
+        }
+    }
+
+    let mut result = conn.query_iter(&source.sql).await.map_err(|e| format!("SQL Error: {}", e))?;
+    let columns: Vec<String> = match result.columns() {
+        Some(cols) => cols.iter().map(|c| c.name_str().into_owned()).collect(),
+        None => return Err("Query did not return a result set".to_string()),
+    };
+    let rows_data: Vec<mysql_async::Row> = result.collect().await.map_err(|e| e.to_string())?;
+    let rows = rows_data
+        .into_iter()
+        .map(|row| (0..columns.len()).map(|i| row.get(i).unwrap_or(mysql_async::Value::NULL)).collect())
+        .collect();
+
+    Ok(FetchedSet { columns, rows })
+}
+
+/// Connection options for a saved server. Credentials are passed as-is rather than through
+/// a URL, so they need no percent-encoding.
+fn connection_opts(config: &DbConfig) -> Result<mysql_async::OptsBuilder, String> {
+    if config.ssh_enabled.unwrap_or(false) {
+        return Err("SSH tunnels are not supported for comparison sources".to_string());
+    }
+    Ok(mysql_async::OptsBuilder::default()
+        .ip_or_hostname(config.host.clone())
+        .tcp_port(config.port)
+        .user(Some(config.user.clone()))
+        .pass(Some(config.pass.clone()))
+        .ssl_opts(config.ssl.unwrap_or(false).then(mysql_async::SslOpts::default)))
+}
+
+async fn run_source(source: &CompareSource, state: &State<'_, AppState>) -> Result<FetchedSet, String> {
+    match &source.connection {
+        Some(config) => {
+            let pool = mysql_async::Pool::new(connection_opts(config)?);
+            let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+            let fetched = fetch_rows(&mut conn, source).await;
+            drop(conn);
+            let _ = pool.disconnect().await;
+            fetched
+        }
+        None => {
+            let pool = {
+                let pool_guard = state.pool.lock().unwrap();
+                pool_guard.as_ref().cloned().ok_or("Not connected")?
+            };
+            let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+            fetch_rows(&mut conn, source).await
+        }
+    }
+}
+
+/// Comparable text form of a cell; NULL stays distinct from every string.
+fn cell_key(val: &mysql_async::Value) -> Option<String> {
+    value_to_text(val)
+}
+
+fn pick(row: &[mysql_async::Value], indices: &[usize]) -> Vec<Option<String>> {
+    indices.iter().map(|&i| cell_key(&row[i])).collect()
+}
+
+fn to_json(row: &[mysql_async::Value]) -> Vec<serde_json::Value> {
+    row.iter().cloned().map(mysql_to_json).collect()
+}
+
+fn column_index(columns: &[String], name: &str) -> Option<usize> {
+    columns.iter().position(|c| c == name)
+        .or_else(|| columns.iter().position(|c| c.eq_ignore_ascii_case(name)))
+}
+
+/// Runs two queries and diffs their result sets, aligning rows by `key_columns`
+/// or, when no key is given, by the full row.
+#[tauri::command]
+pub async fn compare_queries(left: CompareSource, right: CompareSource, options: Option<CompareOptions>, state: State<'_, AppState>) -> Result<CompareResult, String> {
+    let opts = options.unwrap_or_default();
+    let max_rows = opts.max_rows.unwrap_or(1000);
+    let ignored = opts.ignore_columns.unwrap_or_default();
+
+    let left_set = run_source(&left, &state).await.map_err(|e| format!("Left query: {}", e))?;
+    let right_set = run_source(&right, &state).await.map_err(|e| format!("Right query: {}", e))?;
+
+    // Columns are matched by name; only those present on both sides (and not ignored) are compared.
+    let is_ignored = |c: &String| ignored.iter().any(|i| i.eq_ignore_ascii_case(c));
+    let mut columns = Vec::new();
+    let mut left_idx = Vec::new();
+    let mut right_idx = Vec::new();
+    let mut left_only_columns = Vec::new();
+    for (i, c) in left_set.columns.iter().enumerate() {
+        match column_index(&right_set.columns, c) {
+            Some(j) if !is_ignored(c) => {
+                columns.push(c.clone());
+                left_idx.push(i);
+                right_idx.push(j);
+            }
+            Some(_) => {}
+            None => left_only_columns.push(c.clone()),
+        }
+    }
+    let right_only_columns: Vec<String> = right_set.columns.iter()
+        .filter(|c| column_index(&left_set.columns, c).is_none())
+        .cloned()
+        .collect();
+
+    let key_columns = opts.key_columns.unwrap_or_default();
+    let mut key_pos = Vec::new();
+    for k in &key_columns {
+        let pos = column_index(&columns, k).ok_or(format!("Key column `{}` is not present in both result sets", k))?;
+        key_pos.push(pos);
+    }
+
+    let left_rows: Vec<Vec<mysql_async::Value>> = left_set.rows.iter()
+        .map(|r| left_idx.iter().map(|&i| r[i].clone()).collect())
+        .collect();
+    let right_rows: Vec<Vec<mysql_async::Value>> = right_set.rows.iter()
+        .map(|r| right_idx.iter().map(|&i| r[i].clone()).collect())
+        .collect();
+
+    let all_pos: Vec<usize> = (0..columns.len()).collect();
+    let align_pos = if key_pos.is_empty() { &all_pos } else { &key_pos };
+
+    let mut summary = CompareSummary {
+        left_rows: left_rows.len(),
+        right_rows: right_rows.len(),
+        ..Default::default()
+    };
+
+    // Index the right side; duplicates queue up and are paired in result order.
+    let mut right_map: HashMap<Vec<Option<String>>, VecDeque<usize>> = HashMap::new();
+    for (i, row) in right_rows.iter().enumerate() {
+        let entry = right_map.entry(pick(row, align_pos)).or_default();
+        if !entry.is_empty() && !key_pos.is_empty() {
+            summary.duplicate_keys_right += 1;
+        }
+        entry.push_back(i);
+    }
+
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+    let mut changed = Vec::new();
+    let mut seen_left: HashMap<Vec<Option<String>>, usize> = HashMap::new();
+
+    for row in &left_rows {
+        let key = pick(row, align_pos);
+        if !key_pos.is_empty() {
+            let seen = seen_left.entry(key.clone()).or_insert(0);
+            if *seen > 0 {
+                summary.duplicate_keys_left += 1;
+            }
+            *seen += 1;
+        }
+
+        let matched = right_map.get_mut(&key).and_then(|q| q.pop_front());
+        match matched {
+            None => {
+                summary.removed += 1;
+                if removed.len() < max_rows {
+                    removed.push(to_json(row));
+                }
+            }
+            Some(j) => {
+                let other = &right_rows[j];
+                let diffs: Vec<CellDiff> = (0..columns.len())
+                    .filter(|&c| cell_key(&row[c]) != cell_key(&other[c]))
+                    .map(|c| CellDiff {
+                        column: columns[c].clone(),
+                        left: mysql_to_json(row[c].clone()),
+                        right: mysql_to_json(other[c].clone()),
+                    })
+                    .collect();
+                if diffs.is_empty() {
+                    summary.unchanged += 1;
+                } else {
+                    summary.changed += 1;
+                    if changed.len() < max_rows {
+                        changed.push(ChangedRow {
+                            key: key_pos.iter().map(|&p| mysql_to_json(row[p].clone())).collect(),
+                            left: to_json(row),
+                            right: to_json(other),
+                            diffs,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    let mut remaining: Vec<usize> = right_map.into_values().flatten().collect();
+    remaining.sort_unstable();
+    summary.added = remaining.len();
+    for j in remaining.into_iter().take(max_rows) {
+        added.push(to_json(&right_rows[j]));
+    }
+
+    let truncated = summary.added > added.len() || summary.removed > removed.len() || summary.changed > changed.len();
+    let identical = summary.added == 0 && summary.removed == 0 && summary.changed == 0
+        && left_only_columns.is_empty() && right_only_columns.is_empty();
+
+    Ok(CompareResult {
+        columns,
+        left_only_columns,
+        right_only_columns,
+        key_columns,
+        added,
+        removed,
+        changed,
+        summary,
+        truncated,
+        identical,
+    })
+}
@@ -0,0 +1,134 @@
+use tauri::State;
+use crate::state::AppState;
+use crate::commands::common::quote_identifier;
+use mysql_async::prelude::*;
+use serde::Serialize;
+use std::collections::HashMap;
+
+#[derive(Serialize)]
+pub struct CheckConstraint {
+    pub name: String,
+    pub expression: String,
+    pub enforced: Option<bool>, // MySQL only; MariaDB always enforces
+}
+
+/// Fetches rows with `SELECT *` and maps them by column name, since MySQL and MariaDB
+/// expose different columns in these information_schema tables.
+async fn select_by_name<P: Into<mysql_async::Params> + Send>(conn: &mut mysql_async::Conn, sql: &str, params: P) -> Result<Vec<HashMap<String, Option<String>>>, String> {
+    let mut result = conn.exec_iter(sql, params).await.map_err(|e| e.to_string())?;
+    let columns: Vec<String> = result
+        .columns()
+        .map(|cols| cols.iter().map(|c| c.name_str().to_uppercase()).collect())
+        .unwrap_or_default();
+    let rows: Vec<mysql_async::Row> = result.collect().await.map_err(|e| e.to_string())?;
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            columns
+                .iter()
+                .enumerate()
+                .map(|(i, c)| (c.clone(), row.get::<Option<String>, _>(i).flatten()))
+                .collect()
+        })
+        .collect())
+}
+
+#[tauri::command]
+pub async fn get_check_constraints(db: String, table: String, state: State<'_, AppState>) -> Result<Vec<CheckConstraint>, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    // Servers without CHECK support (MySQL < 8.0.16) have no such table.
+    let supported: Option<u64> = conn
+        .query_first(
+            "SELECT COUNT(*) FROM information_schema.TABLES
+             WHERE TABLE_SCHEMA = 'information_schema' AND TABLE_NAME = 'CHECK_CONSTRAINTS'",
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    if supported.unwrap_or(0) == 0 {
+        return Ok(Vec::new());
+    }
+
+    // MySQL names checks per schema; MariaDB per table and adds TABLE_NAME to CHECK_CONSTRAINTS.
+    let clauses = select_by_name(
+        &mut conn,
+        "SELECT * FROM information_schema.CHECK_CONSTRAINTS WHERE CONSTRAINT_SCHEMA = ?",
+        (&db,),
+    )
+    .await?;
+    let owned = select_by_name(
+        &mut conn,
+        "SELECT * FROM information_schema.TABLE_CONSTRAINTS
+         WHERE CONSTRAINT_SCHEMA = ? AND TABLE_NAME = ? AND CONSTRAINT_TYPE = 'CHECK'",
+        (&db, &table),
+    )
+    .await?;
+
+    let text = |row: &HashMap<String, Option<String>>, col: &str| row.get(col).cloned().flatten().unwrap_or_default();
+    let mut checks = Vec::new();
+    for row in &owned {
+        let name = text(row, "CONSTRAINT_NAME");
+        let clause = clauses.iter().find(|c| {
+            text(c, "CONSTRAINT_NAME") == name && c.get("TABLE_NAME").map_or(true, |t| t.as_deref() == Some(table.as_str()))
+        });
+        checks.push(CheckConstraint {
+            expression: clause.map(|c| text(c, "CHECK_CLAUSE")).unwrap_or_default(),
+            enforced: row.get("ENFORCED").cloned().flatten().map(|e| e == "YES"),
+            name,
+        });
+    }
+    checks.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(checks)
+}
+
+#[tauri::command]
+pub async fn add_check_constraint(
+    db: String,
+    table: String,
+    name: Option<String>,
+    expression: String,
+    enforced: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    if expression.trim().is_empty() {
+        return Err("Check expression is required".to_string());
+    }
+    let constraint = match name.as_deref().filter(|n| !n.is_empty()) {
+        Some(n) => format!("CONSTRAINT {} ", quote_identifier(n)),
+        None => String::new(),
+    };
+    // NOT ENFORCED is MySQL syntax; leave it out unless asked for.
+    let enforcement = if enforced == Some(false) { " NOT ENFORCED" } else { "" };
+    let sql = format!(
+        "ALTER TABLE {}.{} ADD {}CHECK ({}){}",
+        quote_identifier(&db), quote_identifier(&table), constraint, expression.trim(), enforcement
+    );
+
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+    conn.query_drop(&sql).await.map_err(|e| e.to_string())?;
+    Ok(sql)
+}
+
+#[tauri::command]
+pub async fn drop_check_constraint(db: String, table: String, name: String, state: State<'_, AppState>) -> Result<(), String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+    // DROP CONSTRAINT is understood by both MySQL 8.0.19+ and MariaDB.
+    conn.query_drop(format!(
+        "ALTER TABLE {}.{} DROP CONSTRAINT {}",
+        quote_identifier(&db), quote_identifier(&table), quote_identifier(&name)
+    ))
+    .await
+    .map_err(|e| e.to_string())
+}
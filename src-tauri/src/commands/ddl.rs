@@ -0,0 +1,113 @@
+use tauri::State;
+use crate::state::AppState;
+use crate::commands::common::quote_identifier;
+use crate::commands::formatter::{self, FormatOptions};
+use crate::commands::sql_lexer::{tokenize, TokenKind};
+use mysql_async::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Default)]
+pub struct DdlOptions {
+    pub strip_definer: Option<bool>,
+    pub strip_auto_increment: Option<bool>,
+    pub format: Option<bool>,
+    pub format_options: Option<FormatOptions>,
+}
+
+#[derive(Serialize)]
+pub struct ObjectDdl {
+    pub object_type: String,
+    pub name: String,
+    pub ddl: String,
+    pub sql_mode: Option<String>, // routines, triggers and events run under their saved sql_mode
+}
+
+/// Removes `DEFINER=user@host` from the head of a CREATE statement.
+pub fn strip_definer(ddl: &str) -> String {
+    let tokens: Vec<_> = tokenize(ddl).into_iter().filter(|t| t.kind != TokenKind::Whitespace).collect();
+    let Some(pos) = tokens.iter().position(|t| t.is_word("DEFINER")) else { return ddl.to_string() };
+    // Only the clause form (`DEFINER =`), not `SQL SECURITY DEFINER`.
+    if !tokens.get(pos + 1).is_some_and(|t| t.text == "=") {
+        return ddl.to_string();
+    }
+    let mut end = pos + 2;
+    if tokens.get(end).is_some_and(|t| t.is_word("CURRENT_USER")) {
+        end += 1;
+        if tokens.get(end).is_some_and(|t| t.kind == TokenKind::OpenParen) && tokens.get(end + 1).is_some_and(|t| t.kind == TokenKind::CloseParen) {
+            end += 2;
+        }
+    } else {
+        // user, then optionally @host; the lexer may read `@'host'` or `@host` as a variable.
+        end += 1;
+        while tokens.get(end).is_some_and(|t| t.text.starts_with('@')) {
+            end += if tokens[end].text == "@" { 2 } else { 1 };
+        }
+    }
+    let start = tokens[pos].start;
+    let stop = tokens.get(end).map(|t| t.start).unwrap_or(ddl.len());
+    format!("{}{}", ddl[..start].trim_end(), if stop < ddl.len() { format!(" {}", &ddl[stop..]) } else { String::new() })
+}
+
+/// Removes the `AUTO_INCREMENT=n` table option (column attributes are kept).
+pub fn strip_auto_increment(ddl: &str) -> String {
+    let tokens: Vec<_> = tokenize(ddl).into_iter().filter(|t| t.kind != TokenKind::Whitespace).collect();
+    for (i, tok) in tokens.iter().enumerate() {
+        if tok.is_word("AUTO_INCREMENT")
+            && tokens.get(i + 1).is_some_and(|t| t.text == "=")
+            && tokens.get(i + 2).is_some_and(|t| t.kind == TokenKind::Number)
+        {
+            let end = tokens[i + 2].start + tokens[i + 2].text.len();
+            return format!("{}{}", ddl[..tok.start].trim_end(), &ddl[end..]);
+        }
+    }
+    ddl.to_string()
+}
+
+/// Reads the DDL of a table, view, trigger, event, procedure, function or sequence via
+/// `SHOW CREATE`. Pretty-formatting is skipped for bodies with several statements
+/// (BEGIN ... END blocks), which the formatter would split.
+#[tauri::command]
+pub async fn get_object_ddl(db: String, object_type: String, name: String, options: Option<DdlOptions>, state: State<'_, AppState>) -> Result<ObjectDdl, String> {
+    let opts = options.unwrap_or_default();
+    let object_type = object_type.to_uppercase();
+    if !["TABLE", "VIEW", "TRIGGER", "EVENT", "PROCEDURE", "FUNCTION", "SEQUENCE"].contains(&object_type.as_str()) {
+        return Err(format!("Unsupported object type: {}", object_type));
+    }
+
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let sql = format!("SHOW CREATE {} {}.{}", object_type, quote_identifier(&db), quote_identifier(&name));
+    let mut result = conn.query_iter(sql).await.map_err(|e| e.to_string())?;
+    let columns: Vec<String> = result
+        .columns()
+        .map(|cols| cols.iter().map(|c| c.name_str().into_owned()).collect())
+        .unwrap_or_default();
+    let rows: Vec<mysql_async::Row> = result.collect().await.map_err(|e| e.to_string())?;
+    let row = rows.into_iter().next().ok_or(format!("{} `{}`.`{}` not found", object_type, db, name))?;
+
+    let ddl_idx = columns
+        .iter()
+        .position(|c| c.starts_with("Create ") || c == "SQL Original Statement")
+        .ok_or("Unexpected SHOW CREATE result")?;
+    let mut ddl: String = row
+        .get::<Option<String>, _>(ddl_idx)
+        .flatten()
+        .ok_or("Definition is not visible; it requires ownership or the SHOW_ROUTINE privilege")?;
+    let sql_mode = columns.iter().position(|c| c == "sql_mode").and_then(|i| row.get::<Option<String>, _>(i).flatten());
+
+    if opts.strip_definer.unwrap_or(false) {
+        ddl = strip_definer(&ddl);
+    }
+    if opts.strip_auto_increment.unwrap_or(false) && object_type == "TABLE" {
+        ddl = strip_auto_increment(&ddl);
+    }
+    if opts.format.unwrap_or(false) && !tokenize(&ddl).iter().any(|t| t.kind == TokenKind::Semicolon) {
+        ddl = formatter::format(&ddl, &opts.format_options.unwrap_or_default());
+    }
+
+    Ok(ObjectDdl { object_type, name, ddl, sql_mode })
+}
@@ -0,0 +1,884 @@
+use serde::Deserialize;
+use crate::commands::sql_lexer::{self, Token, TokenKind};
+
+#[derive(Deserialize, Default, Clone)]
+pub struct FormatOptions {
+    pub keyword_case: Option<String>,   // "upper" (default), "lower", "preserve"
+    pub indent_size: Option<usize>,     // Spaces per level, default 2
+    pub use_tabs: Option<bool>,
+    pub line_width: Option<usize>,      // Lists and conditions that fit stay on one line, default 80
+    pub comma_position: Option<String>, // "trailing" (default) or "leading"
+    pub align: Option<bool>,            // Align aliases, assignments and column definitions
+    pub minify: Option<bool>,           // Collapse to a single line and drop regular comments
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum KeywordCase {
+    Upper,
+    Lower,
+    Preserve,
+}
+
+struct Settings {
+    keyword_case: KeywordCase,
+    indent_unit: String,
+    indent_width: usize,
+    line_width: usize,
+    leading_commas: bool,
+    align: bool,
+}
+
+impl Settings {
+    fn from_options(options: &FormatOptions) -> Self {
+        let keyword_case = match options.keyword_case.as_deref().map(|c| c.to_lowercase()).as_deref() {
+            Some("lower") => KeywordCase::Lower,
+            Some("preserve") => KeywordCase::Preserve,
+            _ => KeywordCase::Upper,
+        };
+        let indent_size = options.indent_size.unwrap_or(2);
+        let (indent_unit, indent_width) = if options.use_tabs.unwrap_or(false) {
+            ("\t".to_string(), 4)
+        } else {
+            (" ".repeat(indent_size), indent_size)
+        };
+
+        Settings {
+            keyword_case,
+            indent_unit,
+            indent_width,
+            line_width: options.line_width.unwrap_or(80).max(20),
+            leading_commas: options.comma_position.as_deref() == Some("leading"),
+            align: options.align.unwrap_or(false),
+        }
+    }
+}
+
+enum Node {
+    Tok(Token),
+    Group { children: Vec<Node>, space_before: bool, closed: bool },
+}
+
+impl Node {
+    fn word(&self) -> Option<String> {
+        match self {
+            Node::Tok(t) if t.kind == TokenKind::Word => Some(t.upper()),
+            _ => None,
+        }
+    }
+
+    fn is_kind(&self, kind: TokenKind) -> bool {
+        matches!(self, Node::Tok(t) if t.kind == kind)
+    }
+}
+
+fn build_tree(tokens: &[Token]) -> Vec<Node> {
+    let mut stack: Vec<(Vec<Node>, bool)> = vec![(Vec::new(), false)];
+    let mut space = false;
+
+    for tok in tokens {
+        match tok.kind {
+            TokenKind::Whitespace => {
+                space = true;
+                continue;
+            }
+            TokenKind::OpenParen => stack.push((Vec::new(), space)),
+            TokenKind::CloseParen if stack.len() > 1 => {
+                let (children, space_before) = stack.pop().unwrap();
+                stack.last_mut().unwrap().0.push(Node::Group { children, space_before, closed: true });
+            }
+            _ => stack.last_mut().unwrap().0.push(Node::Tok(tok.clone())),
+        }
+        space = false;
+    }
+
+    // Unbalanced input: close whatever is still open so nothing gets lost
+    while stack.len() > 1 {
+        let (children, space_before) = stack.pop().unwrap();
+        stack.last_mut().unwrap().0.push(Node::Group { children, space_before, closed: false });
+    }
+    stack.pop().map(|(nodes, _)| nodes).unwrap_or_default()
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ClauseKind {
+    Other,
+    Select,
+    From,
+    Join,
+    On,
+    Using,
+    Where,
+    GroupBy,
+    Having,
+    OrderBy,
+    Limit,
+    Union,
+    Insert,
+    Values,
+    Set,
+    Update,
+    Delete,
+    With,
+    Into,
+    DuplicateKey,
+    Window,
+    Returning,
+    Locking,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Layout {
+    Inline,
+    List,
+    Condition,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Align {
+    None,
+    Alias,
+    Assign,
+    ColumnDef,
+}
+
+impl ClauseKind {
+    fn layout(self) -> Layout {
+        match self {
+            ClauseKind::Select | ClauseKind::From | ClauseKind::GroupBy | ClauseKind::OrderBy
+            | ClauseKind::Set | ClauseKind::Values | ClauseKind::DuplicateKey | ClauseKind::With
+            | ClauseKind::Returning | ClauseKind::Window => Layout::List,
+            ClauseKind::Where | ClauseKind::Having | ClauseKind::On => Layout::Condition,
+            _ => Layout::Inline,
+        }
+    }
+
+    fn align(self) -> Align {
+        match self {
+            ClauseKind::Select => Align::Alias,
+            ClauseKind::Set | ClauseKind::DuplicateKey => Align::Assign,
+            _ => Align::None,
+        }
+    }
+}
+
+struct Clause<'n> {
+    kind: ClauseKind,
+    head: &'n [Node],
+    body: &'n [Node],
+}
+
+/// Rendered inline text, with a trailing line comment split off so separators can go before it.
+struct Inline {
+    text: String,
+    trailing: Option<String>,
+}
+
+impl Inline {
+    fn joined(&self) -> String {
+        match &self.trailing {
+            Some(c) if self.text.is_empty() => c.clone(),
+            Some(c) => format!("{} {}", self.text, c),
+            None => self.text.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Prev {
+    Start,
+    Keyword,
+    Ident,
+    Literal,
+    Operator { unary: bool },
+    Comma,
+    Dot,
+    Group,
+    Comment,
+}
+
+const SELECT_MODIFIERS: &[&str] = &[
+    "ALL", "DISTINCT", "DISTINCTROW", "HIGH_PRIORITY", "STRAIGHT_JOIN", "SQL_SMALL_RESULT", "SQL_BIG_RESULT",
+    "SQL_BUFFER_RESULT", "SQL_NO_CACHE", "SQL_CACHE", "SQL_CALC_FOUND_ROWS",
+];
+const INSERT_MODIFIERS: &[&str] = &["LOW_PRIORITY", "DELAYED", "HIGH_PRIORITY", "IGNORE", "INTO"];
+const UPDATE_MODIFIERS: &[&str] = &["LOW_PRIORITY", "IGNORE"];
+const DELETE_MODIFIERS: &[&str] = &["LOW_PRIORITY", "QUICK", "IGNORE", "FROM"];
+const JOIN_WORDS: &[&str] = &["NATURAL", "LEFT", "RIGHT", "FULL", "OUTER", "INNER", "CROSS"];
+
+// Keywords after which an opening parenthesis gets a space (`IN (`, `VALUES (`), unlike function calls.
+const SPACED_BEFORE_PAREN: &[&str] = &[
+    "AND", "ANY", "AS", "EXISTS", "FROM", "IN", "INTO", "JOIN", "KEY", "NOT", "ON", "OR", "OVER", "SELECT",
+    "SOME", "THEN", "UNION", "USING", "WHEN", "WHERE", "ELSE", "ALL", "INDEX", "RETURN",
+    "LESS", "THAN", "CHECK", "UNIQUE", "REFERENCES", "XOR",
+];
+
+struct Formatter<'s> {
+    s: &'s Settings,
+    ddl: bool,
+}
+
+impl<'s> Formatter<'s> {
+    fn ind(&self, level: usize) -> String {
+        self.s.indent_unit.repeat(level)
+    }
+
+    fn fits(&self, level: usize, text: &str) -> bool {
+        !text.contains('\n') && level * self.s.indent_width + text.chars().count() <= self.s.line_width
+    }
+
+    fn case_word(&self, text: &str, next_is_paren: bool, after_dot: bool) -> String {
+        if after_dot || self.s.keyword_case == KeywordCase::Preserve {
+            return text.to_string();
+        }
+        let is_kw = sql_lexer::is_keyword(text)
+            || (self.ddl && sql_lexer::is_ddl_keyword(text))
+            || (next_is_paren && sql_lexer::is_function(text));
+        if !is_kw {
+            return text.to_string();
+        }
+        match self.s.keyword_case {
+            KeywordCase::Upper => text.to_ascii_uppercase(),
+            KeywordCase::Lower => text.to_ascii_lowercase(),
+            KeywordCase::Preserve => text.to_string(),
+        }
+    }
+
+    fn case_head(&self, text: &str) -> String {
+        match self.s.keyword_case {
+            KeywordCase::Upper => text.to_ascii_uppercase(),
+            KeywordCase::Lower => text.to_ascii_lowercase(),
+            KeywordCase::Preserve => text.to_string(),
+        }
+    }
+
+    fn render_inline(&self, nodes: &[Node], indent: usize, break_groups: bool, start_col: usize) -> Inline {
+        let mut out = String::new();
+        let mut prev = Prev::Start;
+        let mut prev_word = String::new();
+        let mut line_comment_at: Option<usize> = None;
+
+        for (i, node) in nodes.iter().enumerate() {
+            let next_is_paren = matches!(nodes.get(i + 1), Some(Node::Group { .. }));
+            let col = match out.rfind('\n') {
+                Some(pos) => out[pos + 1..].chars().count(),
+                None => start_col + out.chars().count(),
+            };
+
+            match node {
+                Node::Group { children, space_before, closed } => {
+                    let spaced = match prev {
+                        Prev::Start | Prev::Dot => false,
+                        Prev::Comma | Prev::Operator { .. } | Prev::Group | Prev::Comment => true,
+                        Prev::Keyword => SPACED_BEFORE_PAREN.contains(&prev_word.as_str()) || *space_before,
+                        Prev::Ident | Prev::Literal => *space_before,
+                    };
+                    self.push_separator(&mut out, &mut line_comment_at, spaced, indent);
+                    let group = self.render_group(children, *closed, indent, break_groups, col + spaced as usize);
+                    out.push_str(&group);
+                    prev = Prev::Group;
+                }
+                Node::Tok(tok) => {
+                    let spaced = match tok.kind {
+                        TokenKind::Comma | TokenKind::Dot | TokenKind::Semicolon | TokenKind::CloseParen => false,
+                        _ => match prev {
+                            Prev::Start | Prev::Dot => false,
+                            Prev::Operator { unary } => !unary,
+                            _ => true,
+                        },
+                    };
+                    self.push_separator(&mut out, &mut line_comment_at, spaced, indent);
+
+                    prev = match tok.kind {
+                        TokenKind::Word => {
+                            let text = self.case_word(&tok.text, next_is_paren, prev == Prev::Dot);
+                            let is_kw = sql_lexer::is_keyword(&tok.text) || sql_lexer::is_ddl_keyword(&tok.text);
+                            out.push_str(&text);
+                            prev_word = tok.upper();
+                            if is_kw && prev != Prev::Dot { Prev::Keyword } else { Prev::Ident }
+                        }
+                        TokenKind::QuotedIdent | TokenKind::Variable | TokenKind::Placeholder => {
+                            out.push_str(&tok.text);
+                            Prev::Ident
+                        }
+                        TokenKind::String | TokenKind::Number => {
+                            out.push_str(&tok.text);
+                            Prev::Literal
+                        }
+                        TokenKind::Operator => {
+                            out.push_str(&tok.text);
+                            let unary = matches!(tok.text.as_str(), "-" | "+" | "~" | "!")
+                                && matches!(prev, Prev::Start | Prev::Operator { .. } | Prev::Comma | Prev::Keyword);
+                            Prev::Operator { unary }
+                        }
+                        TokenKind::Comma => {
+                            out.push(',');
+                            Prev::Comma
+                        }
+                        TokenKind::Dot => {
+                            out.push('.');
+                            Prev::Dot
+                        }
+                        TokenKind::LineComment => {
+                            line_comment_at = Some(out.len());
+                            out.push_str(tok.text.trim_end());
+                            Prev::Comment
+                        }
+                        TokenKind::BlockComment | TokenKind::ExecComment => {
+                            out.push_str(&tok.text);
+                            Prev::Comment
+                        }
+                        _ => {
+                            out.push_str(&tok.text);
+                            Prev::Literal
+                        }
+                    };
+                }
+            }
+        }
+
+        // A line comment at the very end is handed back separately so callers can append separators first
+        let trailing = line_comment_at.map(|pos| {
+            let comment = out[pos..].to_string();
+            out.truncate(pos);
+            let trimmed = out.trim_end().len();
+            out.truncate(trimmed);
+            comment
+        });
+
+        Inline { text: out, trailing }
+    }
+
+    fn push_separator(&self, out: &mut String, line_comment_at: &mut Option<usize>, spaced: bool, indent: usize) {
+        if line_comment_at.take().is_some() {
+            out.push('\n');
+            out.push_str(&self.ind(indent));
+        } else if spaced {
+            out.push(' ');
+        }
+    }
+
+    fn render_group(&self, children: &[Node], closed: bool, indent: usize, break_groups: bool, col: usize) -> String {
+        let close = if closed { ")" } else { "" };
+
+        if is_subquery(children) {
+            return format!("(\n{}\n{}{}", self.format_dml(children, indent + 1), self.ind(indent), close);
+        }
+
+        let has_commas = children.iter().any(|n| n.is_kind(TokenKind::Comma));
+        let inner = self.render_inline(children, indent + 1, false, col + 1);
+        let one_line = format!("({}{}", inner.text, close);
+        let too_long = col + one_line.chars().count() > self.s.line_width || one_line.contains('\n');
+
+        if has_commas && (break_groups || too_long || inner.trailing.is_some()) {
+            let items = split_top(children, |n| n.is_kind(TokenKind::Comma));
+            let align = if break_groups { Align::ColumnDef } else { Align::None };
+            return format!("(\n{}\n{}{}", self.render_list(&items, indent + 1, align), self.ind(indent), close);
+        }
+
+        match inner.trailing {
+            Some(comment) => format!("({} {}\n{}{}", inner.text, comment, self.ind(indent), close),
+            None => one_line,
+        }
+    }
+
+    /// One item per line at `indent`, honouring comma placement and optional alignment.
+    fn render_list(&self, items: &[&[Node]], indent: usize, align: Align) -> String {
+        let col = indent * self.s.indent_width;
+        let split_at = |item: &[Node]| -> Option<usize> {
+            if !self.s.align {
+                return None;
+            }
+            match align {
+                Align::None => None,
+                Align::Alias => item.iter().rposition(|n| n.word().as_deref() == Some("AS")).filter(|&k| k > 0),
+                Align::Assign => item.iter().position(|n| matches!(n, Node::Tok(t) if t.kind == TokenKind::Operator && t.text == "=")).filter(|&k| k > 0),
+                Align::ColumnDef => match item.first() {
+                    Some(Node::Tok(t)) if t.kind == TokenKind::QuotedIdent => Some(1),
+                    Some(Node::Tok(t)) if t.kind == TokenKind::Word && !sql_lexer::is_keyword(&t.text) => Some(1),
+                    _ => None,
+                }.filter(|&k| k < item.len()),
+            }
+        };
+
+        let mut parts: Vec<(String, Option<String>, Option<String>)> = Vec::new();
+        for item in items {
+            match split_at(item) {
+                Some(k) => {
+                    let left = self.render_inline(&item[..k], indent, false, col);
+                    let right = self.render_inline(&item[k..], indent, false, col);
+                    if left.trailing.is_none() && !left.text.contains('\n') {
+                        parts.push((left.text, Some(right.text), right.trailing));
+                        continue;
+                    }
+                    let whole = self.render_inline(item, indent, false, col);
+                    parts.push((whole.text, None, whole.trailing));
+                }
+                None => {
+                    let whole = self.render_inline(item, indent, false, col);
+                    parts.push((whole.text, None, whole.trailing));
+                }
+            }
+        }
+
+        let pad_to = parts.iter().filter(|p| p.1.is_some()).map(|p| p.0.chars().count()).max().unwrap_or(0);
+        let last = parts.len().saturating_sub(1);
+        let mut lines = Vec::new();
+
+        for (i, (left, right, trailing)) in parts.into_iter().enumerate() {
+            let mut text = match right {
+                Some(r) => format!("{:width$} {}", left, r, width = pad_to),
+                None => left,
+            };
+            if self.s.leading_commas {
+                text = format!("{}{}", if i > 0 { ", " } else { "  " }, text);
+            }
+            if !self.s.leading_commas && i < last {
+                text.push(',');
+            }
+            if let Some(c) = trailing {
+                text = if text.is_empty() { c } else { format!("{} {}", text, c) };
+            }
+            lines.push(format!("{}{}", self.ind(indent), text));
+        }
+
+        lines.join("\n")
+    }
+
+    fn match_clause(&self, nodes: &[Node], i: usize, current: Option<ClauseKind>) -> Option<(ClauseKind, usize)> {
+        let word = nodes[i].word()?;
+        let word_at = |j: usize| nodes.get(j).and_then(|n| n.word());
+        let next_is_group = matches!(nodes.get(i + 1), Some(Node::Group { .. }));
+        let at_start = current.is_none() || current == Some(ClauseKind::With);
+        let with_modifiers = |kind: ClauseKind, modifiers: &[&str]| {
+            let mut j = i + 1;
+            while j < nodes.len() {
+                let is_modifier = word_at(j).is_some_and(|w| modifiers.contains(&w.as_str()));
+                if !is_modifier && !nodes[j].is_kind(TokenKind::ExecComment) {
+                    break;
+                }
+                j += 1;
+            }
+            Some((kind, j - i))
+        };
+
+        match word.as_str() {
+            "SELECT" => with_modifiers(ClauseKind::Select, SELECT_MODIFIERS),
+            "FROM" => Some((ClauseKind::From, 1)),
+            "WHERE" => Some((ClauseKind::Where, 1)),
+            "HAVING" => Some((ClauseKind::Having, 1)),
+            "LIMIT" => Some((ClauseKind::Limit, 1)),
+            "WINDOW" => Some((ClauseKind::Window, 1)),
+            "RETURNING" => Some((ClauseKind::Returning, 1)),
+            "GROUP" if word_at(i + 1).as_deref() == Some("BY") => Some((ClauseKind::GroupBy, 2)),
+            "ORDER" if word_at(i + 1).as_deref() == Some("BY") => Some((ClauseKind::OrderBy, 2)),
+            "JOIN" | "STRAIGHT_JOIN" => Some((ClauseKind::Join, 1)),
+            w if JOIN_WORDS.contains(&w) && !next_is_group => {
+                let mut j = i;
+                while word_at(j).is_some_and(|w| JOIN_WORDS.contains(&w.as_str())) {
+                    j += 1;
+                }
+                (word_at(j).as_deref() == Some("JOIN")).then_some((ClauseKind::Join, j - i + 1))
+            }
+            "UNION" | "EXCEPT" | "INTERSECT" => {
+                let modifier = matches!(word_at(i + 1).as_deref(), Some("ALL") | Some("DISTINCT"));
+                Some((ClauseKind::Union, 1 + modifier as usize))
+            }
+            "ON" if word_at(i + 1).as_deref() == Some("DUPLICATE") && word_at(i + 2).as_deref() == Some("KEY") => {
+                Some((ClauseKind::DuplicateKey, 4))
+            }
+            "ON" if current == Some(ClauseKind::Join) => Some((ClauseKind::On, 1)),
+            "USING" if current == Some(ClauseKind::Join) => Some((ClauseKind::Using, 1)),
+            "SET" if matches!(current, Some(ClauseKind::Update) | Some(ClauseKind::Insert)) => Some((ClauseKind::Set, 1)),
+            "VALUES" | "VALUE" if current == Some(ClauseKind::Insert) && next_is_group => Some((ClauseKind::Values, 1)),
+            "INSERT" | "REPLACE" if at_start && !next_is_group => with_modifiers(ClauseKind::Insert, INSERT_MODIFIERS),
+            "UPDATE" if at_start => with_modifiers(ClauseKind::Update, UPDATE_MODIFIERS),
+            "DELETE" if at_start => with_modifiers(ClauseKind::Delete, DELETE_MODIFIERS),
+            "WITH" if current.is_none() => with_modifiers(ClauseKind::With, &["RECURSIVE"]),
+            "INTO" if current == Some(ClauseKind::Select) => Some((ClauseKind::Into, 1)),
+            "FOR" if matches!(word_at(i + 1).as_deref(), Some("UPDATE") | Some("SHARE")) => Some((ClauseKind::Locking, 2)),
+            "LOCK" if word_at(i + 1).as_deref() == Some("IN") => Some((ClauseKind::Locking, 4.min(nodes.len() - i))),
+            _ => None,
+        }
+    }
+
+    fn split_clauses<'n>(&self, nodes: &'n [Node]) -> (Vec<&'n Node>, Vec<Clause<'n>>) {
+        let mut preamble = Vec::new();
+        let mut clauses: Vec<Clause<'n>> = Vec::new();
+        let mut current: Option<ClauseKind> = None;
+        let mut body_start = 0;
+        let mut i = 0;
+
+        while i < nodes.len() {
+            if let Some((kind, head_len)) = self.match_clause(nodes, i, current) {
+                if let Some(last) = clauses.last_mut() {
+                    last.body = &nodes[body_start..i];
+                } else if i > 0 {
+                    // Anything before the first clause keyword: comments go on their own lines,
+                    // other tokens (e.g. a parenthesised subquery before UNION) become an unnamed clause
+                    let (comments, rest): (Vec<_>, Vec<_>) = (0..i).partition(|&k| matches!(&nodes[k], Node::Tok(t) if t.is_comment()));
+                    if rest.is_empty() {
+                        preamble.extend(comments.into_iter().map(|k| &nodes[k]));
+                    } else {
+                        clauses.push(Clause { kind: ClauseKind::Other, head: &[], body: &nodes[..i] });
+                    }
+                }
+                let head_len = head_len.min(nodes.len() - i);
+                clauses.push(Clause { kind, head: &nodes[i..i + head_len], body: &[] });
+                current = Some(kind);
+                i += head_len;
+                body_start = i;
+                continue;
+            }
+            i += 1;
+        }
+
+        match clauses.last_mut() {
+            Some(last) => last.body = &nodes[body_start..],
+            None => clauses.push(Clause { kind: ClauseKind::Other, head: &[], body: nodes }),
+        }
+
+        (preamble, clauses)
+    }
+
+    fn format_dml(&self, nodes: &[Node], indent: usize) -> String {
+        let (preamble, clauses) = self.split_clauses(nodes);
+        let mut lines: Vec<String> = preamble.iter().map(|n| match n {
+            Node::Tok(t) => format!("{}{}", self.ind(indent), t.text.trim_end()),
+            Node::Group { .. } => String::new(),
+        }).collect();
+        let mut prev_kind: Option<ClauseKind> = None;
+
+        for clause in clauses {
+            let head = clause.head.iter().map(|n| match n {
+                Node::Tok(t) if t.kind == TokenKind::Word => self.case_head(&t.text),
+                Node::Tok(t) => t.text.clone(),
+                Node::Group { .. } => String::new(),
+            }).collect::<Vec<_>>().join(" ");
+            let head_col = indent * self.s.indent_width + head.chars().count() + 1;
+
+            match clause.kind.layout() {
+                Layout::Inline => {
+                    let body = self.render_inline(clause.body, indent, false, head_col);
+                    let text = [head, body.joined()].iter().filter(|s| !s.is_empty()).cloned().collect::<Vec<_>>().join(" ");
+                    if clause.kind == ClauseKind::Using && prev_kind == Some(ClauseKind::Join) && body.trailing.is_none() {
+                        if let Some(last) = lines.last_mut() {
+                            if last.chars().count() + 1 + text.chars().count() <= self.s.line_width {
+                                last.push(' ');
+                                last.push_str(&text);
+                                prev_kind = Some(clause.kind);
+                                continue;
+                            }
+                        }
+                    }
+                    let level = if clause.kind == ClauseKind::Using { indent + 1 } else { indent };
+                    lines.push(format!("{}{}", self.ind(level), text));
+                }
+                Layout::List => {
+                    let items = split_top(clause.body, |n| n.is_kind(TokenKind::Comma));
+                    let rendered: Vec<Inline> = items.iter().map(|item| self.render_inline(item, indent, false, head_col)).collect();
+                    let one_line = format!("{} {}", head, rendered.iter().map(|r| r.text.clone()).collect::<Vec<_>>().join(", "));
+                    let has_comments = rendered.iter().any(|r| r.trailing.is_some());
+
+                    if items.len() <= 1 {
+                        let body = rendered.first().map(|r| r.joined()).unwrap_or_default();
+                        lines.push(format!("{}{} {}", self.ind(indent), head, body).trim_end().to_string());
+                    } else if !has_comments && self.fits(indent, &one_line) {
+                        lines.push(format!("{}{}", self.ind(indent), one_line));
+                    } else {
+                        lines.push(format!("{}{}", self.ind(indent), head));
+                        lines.push(self.render_list(&items, indent + 1, clause.kind.align()));
+                    }
+                }
+                Layout::Condition => {
+                    let conditions = split_conditions(clause.body);
+                    let rendered: Vec<(Option<String>, Inline)> = conditions.iter().enumerate().map(|(i, (op, cond))| {
+                        let level = if i == 0 { indent } else { indent + 1 };
+                        let op = op.map(|o| self.case_word(&o.text, false, false));
+                        (op, self.render_inline(cond, level, false, level * self.s.indent_width))
+                    }).collect();
+
+                    let mut one_line = head.clone();
+                    for (op, cond) in &rendered {
+                        if let Some(op) = op {
+                            one_line.push(' ');
+                            one_line.push_str(op);
+                        }
+                        one_line.push(' ');
+                        one_line.push_str(&cond.text);
+                    }
+                    let has_comments = rendered.iter().any(|(_, c)| c.trailing.is_some());
+                    let single = !has_comments && self.fits(indent, &one_line);
+
+                    // ON after a JOIN stays on the join line when there is room for it
+                    if clause.kind == ClauseKind::On && prev_kind == Some(ClauseKind::Join) && single {
+                        if let Some(last) = lines.last_mut() {
+                            if !last.contains('\n') && last.chars().count() + 1 + one_line.chars().count() <= self.s.line_width {
+                                last.push(' ');
+                                last.push_str(&one_line);
+                                prev_kind = Some(clause.kind);
+                                continue;
+                            }
+                        }
+                    }
+
+                    let level = if clause.kind == ClauseKind::On { indent + 1 } else { indent };
+                    if single {
+                        lines.push(format!("{}{}", self.ind(level), one_line));
+                    } else {
+                        for (i, (op, cond)) in rendered.iter().enumerate() {
+                            if i == 0 {
+                                lines.push(format!("{}{} {}", self.ind(level), head, cond.joined()));
+                            } else {
+                                let op = op.clone().unwrap_or_default();
+                                lines.push(format!("{}{} {}", self.ind(level + 1), op, cond.joined()));
+                            }
+                        }
+                    }
+                }
+            }
+            prev_kind = Some(clause.kind);
+        }
+
+        lines.join("\n")
+    }
+
+    fn format_ddl(&self, nodes: &[Node]) -> String {
+        let words: Vec<Option<String>> = nodes.iter().map(|n| n.word()).collect();
+
+        // CREATE VIEW ... AS SELECT: keep the header on one line and lay out the query like any other
+        for (k, w) in words.iter().enumerate() {
+            if w.as_deref() != Some("AS") {
+                continue;
+            }
+            let next_is_query = match nodes.get(k + 1) {
+                Some(Node::Group { children, .. }) => is_subquery(children),
+                Some(n) => matches!(n.word().as_deref(), Some("SELECT") | Some("WITH")),
+                None => false,
+            };
+            if next_is_query {
+                let head = self.render_inline(&nodes[..=k], 0, false, 0).joined();
+                return format!("{}\n{}", head, self.format_dml(&nodes[k + 1..], 0));
+            }
+        }
+
+        // ALTER TABLE t spec, spec, ...: one specification per line
+        if words.first().and_then(|w| w.as_deref()) == Some("ALTER") {
+            if let Some(t) = words.iter().position(|w| w.as_deref() == Some("TABLE")) {
+                let mut name_end = t + 2;
+                if nodes.get(t + 2).is_some_and(|n| n.is_kind(TokenKind::Dot)) {
+                    name_end = t + 4;
+                }
+                let name_end = name_end.min(nodes.len());
+                let specs = split_top(&nodes[name_end..], |n| n.is_kind(TokenKind::Comma));
+                let head = self.render_inline(&nodes[..name_end], 0, false, 0).joined();
+                if specs.len() > 1 {
+                    return format!("{}\n{}", head, self.render_list(&specs, 1, Align::None));
+                }
+            }
+        }
+
+        let is_create_table = words.first().and_then(|w| w.as_deref()) == Some("CREATE")
+            && words.iter().take_while(|w| w.is_some()).any(|w| w.as_deref() == Some("TABLE"));
+        self.render_inline(nodes, 0, is_create_table, 0).joined()
+    }
+
+    fn format_statement(&self, tokens: &[Token]) -> String {
+        let nodes = build_tree(tokens);
+        let first = nodes.iter().find(|n| !matches!(n, Node::Tok(t) if t.is_comment()));
+
+        let is_dml = match first {
+            Some(Node::Group { children, .. }) => is_subquery(children),
+            Some(n) => matches!(
+                n.word().as_deref(),
+                Some("SELECT") | Some("WITH") | Some("INSERT") | Some("REPLACE") | Some("UPDATE") | Some("DELETE")
+            ),
+            None => false,
+        };
+        let is_ddl = matches!(first.and_then(|n| n.word()).as_deref(), Some("CREATE") | Some("ALTER"));
+
+        if is_dml {
+            self.format_dml(&nodes, 0)
+        } else if is_ddl {
+            Formatter { s: self.s, ddl: true }.format_ddl(&nodes)
+        } else {
+            self.render_inline(&nodes, 0, false, 0).joined()
+        }
+    }
+
+    fn minify_statement(&self, tokens: &[Token]) -> String {
+        let kept: Vec<&Token> = tokens.iter()
+            .filter(|t| !matches!(t.kind, TokenKind::Whitespace | TokenKind::LineComment | TokenKind::BlockComment))
+            .collect();
+        let ddl = matches!(kept.first().map(|t| t.upper()).as_deref(), Some("CREATE") | Some("ALTER"));
+        let f = Formatter { s: self.s, ddl };
+
+        let mut out = String::new();
+        for (i, tok) in kept.iter().enumerate() {
+            let text = if tok.kind == TokenKind::Word {
+                let next_is_paren = kept.get(i + 1).is_some_and(|n| n.kind == TokenKind::OpenParen);
+                let after_dot = i > 0 && kept[i - 1].kind == TokenKind::Dot;
+                f.case_word(&tok.text, next_is_paren, after_dot)
+            } else {
+                tok.text.clone()
+            };
+
+            let glue = |c: char| c.is_alphanumeric() || matches!(c, '_' | '$' | '@' | '`' | '\'' | '"');
+            let needs_space = match (out.chars().last(), text.chars().next()) {
+                (Some(a), Some(b)) => (glue(a) && glue(b))
+                    || (a == ')' && glue(b))
+                    || (a == '-' && b == '-')
+                    || (a == '/' && b == '*')
+                    || tok.kind == TokenKind::ExecComment
+                    || (i > 0 && kept[i - 1].kind == TokenKind::ExecComment),
+                _ => false,
+            };
+            if needs_space {
+                out.push(' ');
+            }
+            out.push_str(&text);
+        }
+        out
+    }
+}
+
+fn is_subquery(children: &[Node]) -> bool {
+    match children.iter().find(|n| !matches!(n, Node::Tok(t) if t.is_comment())) {
+        Some(Node::Group { children, .. }) => is_subquery(children),
+        Some(n) => matches!(n.word().as_deref(), Some("SELECT") | Some("WITH")),
+        None => false,
+    }
+}
+
+fn split_top(nodes: &[Node], is_separator: impl Fn(&Node) -> bool) -> Vec<&[Node]> {
+    let mut items = Vec::new();
+    let mut start = 0;
+    for (i, n) in nodes.iter().enumerate() {
+        if is_separator(n) {
+            items.push(&nodes[start..i]);
+            start = i + 1;
+        }
+    }
+    if start < nodes.len() || !items.is_empty() {
+        items.push(&nodes[start..]);
+    }
+    items
+}
+
+/// Splits a condition on top-level AND / OR / XOR, keeping `BETWEEN x AND y` together.
+fn split_conditions(nodes: &[Node]) -> Vec<(Option<&Token>, &[Node])> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut op: Option<&Token> = None;
+    let mut in_between = false;
+    let mut case_depth = 0;
+
+    for (i, n) in nodes.iter().enumerate() {
+        let word = n.word();
+        match word.as_deref() {
+            Some("BETWEEN") => in_between = true,
+            Some("CASE") => case_depth += 1,
+            Some("END") if case_depth > 0 => case_depth -= 1,
+            Some("AND") if in_between => in_between = false,
+            Some("AND") | Some("OR") | Some("XOR") if case_depth == 0 && i > start => {
+                parts.push((op, &nodes[start..i]));
+                op = match n {
+                    Node::Tok(t) => Some(t),
+                    _ => None,
+                };
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push((op, &nodes[start..]));
+    parts
+}
+
+/// Formats (or minifies) one or more MySQL statements. Comments and executable comments are kept.
+pub fn format(sql: &str, options: &FormatOptions) -> String {
+    let settings = Settings::from_options(options);
+    let formatter = Formatter { s: &settings, ddl: false };
+    let minify = options.minify.unwrap_or(false);
+
+    let tokens = sql_lexer::tokenize(sql);
+    let statements = sql_lexer::split_statements(&tokens);
+    let last_semicolon = tokens.iter().rev().find(|t| t.kind == TokenKind::Semicolon).map(|t| t.start);
+
+    let mut parts = Vec::new();
+    for (i, stmt) in statements.iter().enumerate() {
+        let text = if minify {
+            formatter.minify_statement(stmt)
+        } else {
+            formatter.format_statement(stmt)
+        };
+        if text.is_empty() {
+            continue;
+        }
+
+        let stmt_end = stmt.last().map(|t| t.end).unwrap_or(0);
+        let terminated = i + 1 < statements.len() || last_semicolon.is_some_and(|pos| pos >= stmt_end);
+        let only_comments = stmt.iter().all(|t| matches!(t.kind, TokenKind::Whitespace | TokenKind::LineComment | TokenKind::BlockComment));
+        let needs_newline = sql_lexer::tokenize(&text).iter().rev()
+            .find(|t| t.kind != TokenKind::Whitespace)
+            .is_some_and(|t| t.kind == TokenKind::LineComment);
+
+        let mut part = text;
+        if terminated && !only_comments {
+            if needs_newline {
+                part.push('\n');
+            }
+            part.push(';');
+        }
+        parts.push(part);
+    }
+
+    if minify {
+        parts.join(" ")
+    } else {
+        parts.join("\n\n")
+    }
+}
+
+#[tauri::command]
+pub async fn format_sql(sql: String, options: Option<FormatOptions>) -> Result<String, String> {
+    let opts = options.unwrap_or_default();
+    Ok(format(&sql, &opts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fmt(sql: &str) -> String {
+        format(sql, &FormatOptions::default())
+    }
+
+    #[test]
+    fn cases_keywords() {
+        assert_eq!(fmt("select a from t where b = 1"), "SELECT a\nFROM t\nWHERE b = 1");
+        let lower = FormatOptions { keyword_case: Some("lower".to_string()), ..Default::default() };
+        assert_eq!(format("SELECT a FROM t", &lower), "select a\nfrom t");
+        let preserve = FormatOptions { keyword_case: Some("preserve".to_string()), ..Default::default() };
+        assert_eq!(format("Select a From t", &preserve), "Select a\nFrom t");
+    }
+
+    #[test]
+    fn indents_subqueries() {
+        assert_eq!(
+            fmt("select a from t where b in (select b from u where c = 1 and d = 2 and e = 3 and f = 4 and g = 5 and h = 6)"),
+            "SELECT a\nFROM t\nWHERE b IN (\n  SELECT b\n  FROM u\n  WHERE c = 1 AND d = 2 AND e = 3 AND f = 4 AND g = 5 AND h = 6\n)"
+        );
+    }
+
+    #[test]
+    fn keeps_comments_unless_minified() {
+        assert_eq!(fmt("select a -- first\n, b /* second */ from t"), "SELECT\n  a, -- first\n  b /* second */\nFROM t");
+        let minify = FormatOptions { minify: Some(true), ..Default::default() };
+        assert_eq!(format("select a -- first\n, b /* second */ from t", &minify), "SELECT a,b FROM t");
+    }
+
+    #[test]
+    fn leaves_literals_alone() {
+        assert_eq!(fmt("select 'select  from', `from  where`, \"and\" from `t`"), "SELECT 'select  from', `from  where`, \"and\"\nFROM `t`");
+    }
+}
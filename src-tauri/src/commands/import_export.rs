@@ -1,5 +1,6 @@
 use tauri::State;
 use crate::state::AppState;
+use crate::commands::common::{escape_html, sql_literal, value_to_text};
 use mysql_async::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -375,3 +376,315 @@ pub async fn import_csv(
 
     Ok(count)
 }
+
+#[derive(Deserialize, Default)]
+pub struct QueryExportOptions {
+    pub format: String, // "csv", "tsv", "json", "ndjson", "markdown", "html", "sql", "xlsx"
+    pub include_headers: Option<bool>,
+    pub null_value: Option<String>,
+    pub delimiter: Option<String>,
+    pub line_ending: Option<String>, // "lf", "crlf"
+    pub table_name: Option<String>,  // target table for "sql"
+    pub rows_per_insert: Option<usize>,
+}
+
+struct ExportColumn {
+    name: String,
+    numeric: bool,
+}
+
+fn is_numeric_column(col: &mysql_async::Column) -> bool {
+    use mysql_async::consts::ColumnType::*;
+    matches!(
+        col.column_type(),
+        MYSQL_TYPE_TINY
+            | MYSQL_TYPE_SHORT
+            | MYSQL_TYPE_LONG
+            | MYSQL_TYPE_INT24
+            | MYSQL_TYPE_LONGLONG
+            | MYSQL_TYPE_FLOAT
+            | MYSQL_TYPE_DOUBLE
+            | MYSQL_TYPE_DECIMAL
+            | MYSQL_TYPE_NEWDECIMAL
+            | MYSQL_TYPE_YEAR
+    )
+}
+
+fn csv_field(s: &str, delimiter: &str) -> String {
+    if s.contains(delimiter) || s.contains('"') || s.contains('\n') || s.contains('\r') {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn tsv_field(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn markdown_field(s: &str) -> String {
+    s.replace('|', "\\|").replace("\r\n", "<br>").replace('\n', "<br>")
+}
+
+/// JSON rendering of a cell. Numeric columns stay numbers when the value parses as one.
+fn json_field(val: &mysql_async::Value, numeric: bool) -> String {
+    match value_to_text(val) {
+        None => "null".to_string(),
+        Some(text) => {
+            if numeric && text.parse::<f64>().map(|n| n.is_finite()).unwrap_or(false) {
+                text
+            } else {
+                serde_json::Value::String(text).to_string()
+            }
+        }
+    }
+}
+
+enum ExportSink {
+    Text(tokio::io::BufWriter<tokio::fs::File>),
+    Xlsx(Box<rust_xlsxwriter::Workbook>),
+}
+
+struct QueryExporter {
+    format: String,
+    columns: Vec<ExportColumn>,
+    include_headers: bool,
+    null_value: String,
+    delimiter: String,
+    eol: String,
+    table_name: String,
+    rows_per_insert: usize,
+    rows: u64,
+    sink: ExportSink,
+}
+
+impl QueryExporter {
+    async fn write(&mut self, s: &str) -> Result<(), String> {
+        use tokio::io::AsyncWriteExt;
+        if let ExportSink::Text(file) = &mut self.sink {
+            file.write_all(s.as_bytes()).await.map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    fn worksheet(&mut self) -> Result<&mut rust_xlsxwriter::Worksheet, String> {
+        match &mut self.sink {
+            ExportSink::Xlsx(workbook) => workbook.worksheet_from_index(0).map_err(|e| e.to_string()),
+            ExportSink::Text(_) => Err("Not an XLSX export".to_string()),
+        }
+    }
+
+    fn text_or_null(&self, val: &mysql_async::Value) -> String {
+        value_to_text(val).unwrap_or_else(|| self.null_value.clone())
+    }
+
+    async fn begin(&mut self) -> Result<(), String> {
+        let eol = self.eol.clone();
+        let names: Vec<String> = self.columns.iter().map(|c| c.name.clone()).collect();
+        match self.format.as_str() {
+            "csv" if self.include_headers => {
+                let line = names.iter().map(|n| csv_field(n, &self.delimiter)).collect::<Vec<_>>().join(&self.delimiter);
+                self.write(&format!("{}{}", line, eol)).await?;
+            }
+            "tsv" if self.include_headers => {
+                let line = names.iter().map(|n| tsv_field(n)).collect::<Vec<_>>().join("\t");
+                self.write(&format!("{}{}", line, eol)).await?;
+            }
+            "json" => self.write("[").await?,
+            "markdown" => {
+                let head = names.iter().map(|n| markdown_field(n)).collect::<Vec<_>>().join(" | ");
+                let sep = vec!["---"; names.len()].join(" | ");
+                self.write(&format!("| {} |{}| {} |{}", head, eol, sep, eol)).await?;
+            }
+            "html" => {
+                let mut out = format!("<!DOCTYPE html>{eol}<html>{eol}<head>{eol}<meta charset=\"utf-8\">{eol}<title>{}</title>{eol}</head>{eol}<body>{eol}<table>{eol}", escape_html(&self.table_name), eol = eol);
+                if self.include_headers {
+                    out.push_str("<thead><tr>");
+                    for n in &names {
+                        out.push_str(&format!("<th>{}</th>", escape_html(n)));
+                    }
+                    out.push_str(&format!("</tr></thead>{}", eol));
+                }
+                out.push_str(&format!("<tbody>{}", eol));
+                self.write(&out).await?;
+            }
+            "xlsx" if self.include_headers => {
+                let bold = rust_xlsxwriter::Format::new().set_bold();
+                let worksheet = self.worksheet()?;
+                for (i, n) in names.iter().enumerate() {
+                    worksheet.write_string_with_format(0, i as u16, n, &bold).map_err(|e| e.to_string())?;
+                }
+                worksheet.set_freeze_panes(1, 0).map_err(|e| e.to_string())?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn row(&mut self, values: &[mysql_async::Value]) -> Result<(), String> {
+        let eol = self.eol.clone();
+        match self.format.as_str() {
+            "csv" => {
+                let line = values.iter().map(|v| csv_field(&self.text_or_null(v), &self.delimiter)).collect::<Vec<_>>().join(&self.delimiter);
+                self.write(&format!("{}{}", line, eol)).await?;
+            }
+            "tsv" => {
+                let line = values.iter().map(|v| tsv_field(&self.text_or_null(v))).collect::<Vec<_>>().join("\t");
+                self.write(&format!("{}{}", line, eol)).await?;
+            }
+            "json" | "ndjson" => {
+                let fields: Vec<String> = self.columns.iter().zip(values).map(|(c, v)| {
+                    format!("{}: {}", serde_json::Value::String(c.name.clone()), json_field(v, c.numeric))
+                }).collect();
+                let object = format!("{{{}}}", fields.join(", "));
+                if self.format == "json" {
+                    let sep = if self.rows == 0 { "" } else { "," };
+                    self.write(&format!("{}{}  {}", sep, eol, object)).await?;
+                } else {
+                    self.write(&format!("{}{}", object, eol)).await?;
+                }
+            }
+            "markdown" => {
+                let line = values.iter().map(|v| markdown_field(&self.text_or_null(v))).collect::<Vec<_>>().join(" | ");
+                self.write(&format!("| {} |{}", line, eol)).await?;
+            }
+            "html" => {
+                let mut out = String::from("<tr>");
+                for v in values {
+                    out.push_str(&format!("<td>{}</td>", escape_html(&self.text_or_null(v))));
+                }
+                out.push_str("</tr>");
+                out.push_str(&eol);
+                self.write(&out).await?;
+            }
+            "sql" => {
+                let literals: Vec<String> = self.columns.iter().zip(values).map(|(c, v)| match v {
+                    mysql_async::Value::Bytes(b) if c.numeric && std::str::from_utf8(b).map(|s| s.parse::<f64>().is_ok()).unwrap_or(false) => {
+                        String::from_utf8_lossy(b).into_owned()
+                    }
+                    _ => sql_literal(v),
+                }).collect();
+                let tuple = format!("({})", literals.join(", "));
+                if self.rows % self.rows_per_insert as u64 == 0 {
+                    if self.rows > 0 {
+                        self.write(&format!(";{}", eol)).await?;
+                    }
+                    let cols: Vec<String> = self.columns.iter().map(|c| format!("`{}`", c.name.replace('`', "``"))).collect();
+                    let header = format!("INSERT INTO `{}` ({}) VALUES{}", self.table_name.replace('`', "``"), cols.join(", "), eol);
+                    self.write(&format!("{}{}", header, tuple)).await?;
+                } else {
+                    self.write(&format!(",{}{}", eol, tuple)).await?;
+                }
+            }
+            "xlsx" => {
+                let row_idx = self.rows as u32 + if self.include_headers { 1 } else { 0 };
+                let null_value = self.null_value.clone();
+                let numeric: Vec<bool> = self.columns.iter().map(|c| c.numeric).collect();
+                let worksheet = self.worksheet()?;
+                for (i, v) in values.iter().enumerate() {
+                    let col = i as u16;
+                    let res = match value_to_text(v) {
+                        None if null_value.is_empty() => continue,
+                        None => worksheet.write_string(row_idx, col, &null_value),
+                        Some(text) => match text.parse::<f64>() {
+                            Ok(n) if numeric[i] && n.is_finite() => worksheet.write_number(row_idx, col, n),
+                            _ => worksheet.write_string(row_idx, col, text),
+                        },
+                    };
+                    res.map_err(|e| e.to_string())?;
+                }
+            }
+            _ => {}
+        }
+        self.rows += 1;
+        Ok(())
+    }
+
+    async fn finish(mut self, file_path: &str) -> Result<u64, String> {
+        use tokio::io::AsyncWriteExt;
+        let eol = self.eol.clone();
+        match self.format.as_str() {
+            "json" => {
+                let close = if self.rows == 0 { "]".to_string() } else { format!("{}]", eol) };
+                self.write(&format!("{}{}", close, eol)).await?;
+            }
+            "html" => self.write(&format!("</tbody>{eol}</table>{eol}</body>{eol}</html>{eol}", eol = eol)).await?,
+            "sql" if self.rows > 0 => self.write(&format!(";{}", eol)).await?,
+            _ => {}
+        }
+        match self.sink {
+            ExportSink::Text(mut file) => file.flush().await.map_err(|e| e.to_string())?,
+            ExportSink::Xlsx(mut workbook) => workbook.save(file_path).map_err(|e| e.to_string())?,
+        }
+        Ok(self.rows)
+    }
+}
+
+/// Re-runs `sql` and streams its result set to `file_path` in the requested format.
+/// Returns the number of exported rows.
+#[tauri::command]
+pub async fn export_query_result(sql: String, db: Option<String>, file_path: String, options: QueryExportOptions, state: State<'_, AppState>) -> Result<u64, String> {
+    let format = options.format.to_lowercase();
+    if !["csv", "tsv", "json", "ndjson", "markdown", "html", "sql", "xlsx"].contains(&format.as_str()) {
+        return Err(format!("Unsupported export format: {}", options.format));
+    }
+    let table_name = options.table_name.clone().unwrap_or_default();
+    if format == "sql" && table_name.trim().is_empty() {
+        return Err("A target table name is required for SQL export".to_string());
+    }
+    let delimiter = options.delimiter.clone().filter(|d| !d.is_empty()).unwrap_or_else(|| ",".to_string());
+    let eol = match options.line_ending.as_deref() {
+        Some("crlf") | Some("\r\n") => "\r\n",
+        _ => "\n",
+    };
+
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    if let Some(db_name) = db {
+        if !db_name.is_empty() {
+            conn.query_drop(format!("USE `{}`", db_name)).await.map_err(|e| e.to_string())?;
+        }
+    }
+
+    let mut result = conn.query_iter(&sql).await.map_err(|e| format!("SQL Error: {}", e))?;
+    let columns: Vec<ExportColumn> = match result.columns() {
+        Some(cols) => cols.iter().map(|c| ExportColumn { name: c.name_str().into_owned(), numeric: is_numeric_column(c) }).collect(),
+        None => return Err("Query did not return a result set".to_string()),
+    };
+
+    let sink = if format == "xlsx" {
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        workbook.add_worksheet_with_constant_memory();
+        ExportSink::Xlsx(Box::new(workbook))
+    } else {
+        let file = tokio::fs::File::create(&file_path).await.map_err(|e| e.to_string())?;
+        ExportSink::Text(tokio::io::BufWriter::new(file))
+    };
+
+    let mut exporter = QueryExporter {
+        format,
+        columns,
+        include_headers: options.include_headers.unwrap_or(true),
+        null_value: options.null_value.clone().unwrap_or_default(),
+        delimiter,
+        eol: eol.to_string(),
+        table_name,
+        rows_per_insert: options.rows_per_insert.unwrap_or(100).max(1),
+        rows: 0,
+        sink,
+    };
+
+    exporter.begin().await?;
+    while let Some(row) = result.next().await.map_err(|e| e.to_string())? {
+        let values: Vec<mysql_async::Value> = (0..row.len()).map(|i| row.get(i).unwrap_or(mysql_async::Value::NULL)).collect();
+        exporter.row(&values).await?;
+    }
+    exporter.finish(&file_path).await
+}
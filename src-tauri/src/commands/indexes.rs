@@ -11,6 +11,7 @@ pub struct IndexInfo {
     pub seq_in_index: u32,
     pub index_type: String,
     pub is_primary: bool,
+    pub comment: String,
 }
 
 #[tauri::command]
@@ -37,6 +38,7 @@ pub async fn get_indexes(db: String, table: String, state: State<'_, AppState>)
             seq_in_index: row.get::<Option<u32>, _>("Seq_in_index").flatten().unwrap_or(1),
             index_type: row.get::<Option<String>, _>("Index_type").flatten().unwrap_or_else(|| "BTREE".to_string()),
             is_primary: key_name == "PRIMARY",
+            comment: row.get::<Option<String>, _>("Index_comment").flatten().unwrap_or_default(),
         });
     }
     Ok(indexes)
@@ -0,0 +1,663 @@
+use tauri::State;
+use crate::state::AppState;
+use crate::commands::sql_lexer::{self, Token, TokenKind};
+use mysql_async::prelude::*;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Serialize)]
+pub struct LintWarning {
+    pub rule: String,
+    pub severity: String, // "error", "warning", "info"
+    pub message: String,
+    pub start: usize,     // Byte offsets into the submitted SQL
+    pub end: usize,
+    pub line: usize,      // 1-based, columns counted in characters
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+type TableKey = (String, String); // (schema, table), lowercased
+
+#[derive(Default)]
+struct SchemaInfo {
+    columns: HashMap<TableKey, Vec<(String, bool)>>, // column name (lowercased) -> nullable
+    indexed: HashMap<TableKey, Vec<(String, String)>>, // leading column (lowercased) -> index name
+}
+
+impl SchemaInfo {
+    fn column(&self, key: &TableKey, name: &str) -> Option<bool> {
+        let name = name.to_lowercase();
+        self.columns.get(key)?.iter().find(|(c, _)| *c == name).map(|(_, nullable)| *nullable)
+    }
+
+    fn index_on(&self, key: &TableKey, name: &str) -> Option<String> {
+        let name = name.to_lowercase();
+        self.indexed.get(key)?.iter().find(|(c, _)| *c == name).map(|(_, idx)| idx.clone())
+    }
+}
+
+struct Sig<'t> {
+    tok: &'t Token,
+    depth: usize,
+    scope: usize,
+    clause: &'static str, // Last clause keyword seen at this token's query level
+}
+
+#[derive(Default)]
+struct Scope {
+    depth: usize,
+    tables: Vec<TableRef>,
+    aliases: HashSet<String>,
+    using_cols: HashSet<String>,
+    natural: bool,
+    has_where: bool,
+}
+
+struct TableRef {
+    db: Option<String>,
+    name: String,
+    alias: Option<String>,
+}
+
+impl TableRef {
+    /// Schema and table as written, for querying the server.
+    fn qualified(&self, default_db: Option<&str>) -> Option<(String, String)> {
+        let db = self.db.as_deref().or(default_db)?;
+        Some((db.to_string(), self.name.clone()))
+    }
+
+    fn key(&self, default_db: Option<&str>) -> Option<TableKey> {
+        self.qualified(default_db).map(|(db, name)| (db.to_lowercase(), name.to_lowercase()))
+    }
+
+    fn matches(&self, qualifier: &str) -> bool {
+        match &self.alias {
+            Some(alias) => alias.eq_ignore_ascii_case(qualifier),
+            None => self.name.eq_ignore_ascii_case(qualifier),
+        }
+    }
+}
+
+struct ColumnRef {
+    idx: usize, // Index into `sig` of the column name token
+    qualifier: Option<String>,
+    name: String,
+}
+
+struct Analysis<'t> {
+    sig: Vec<Sig<'t>>,
+    close_of: HashMap<usize, usize>,
+    scopes: Vec<Scope>,
+    columns: Vec<ColumnRef>,
+}
+
+const CLAUSE_WORDS: &[&str] = &[
+    "SELECT", "FROM", "WHERE", "GROUP", "HAVING", "ORDER", "LIMIT", "ON", "USING", "SET", "VALUES", "UPDATE",
+    "DELETE", "INSERT", "UNION", "EXCEPT", "INTERSECT", "WINDOW", "INTO", "JOIN", "STRAIGHT_JOIN",
+];
+
+// Words after which an unqualified identifier is read as a column reference rather than an alias.
+const EXPRESSION_LEADERS: &[&str] = &[
+    "SELECT", "WHERE", "ON", "AND", "OR", "XOR", "NOT", "BY", "HAVING", "SET", "WHEN", "THEN", "ELSE", "IN",
+    "BETWEEN", "LIKE", "IS", "DISTINCT", "CASE", "REGEXP", "RLIKE", "DIV", "MOD", "ALL", "ANY", "SOME",
+];
+
+const COMPARISONS: &[&str] = &["=", "<", ">", "<=", ">=", "<>", "!=", "<=>"];
+
+fn unquote(text: &str) -> String {
+    if text.len() >= 2 && text.starts_with('`') && text.ends_with('`') {
+        text[1..text.len() - 1].replace("``", "`")
+    } else {
+        text.to_string()
+    }
+}
+
+fn is_name(tok: &Token) -> bool {
+    tok.kind == TokenKind::QuotedIdent || (tok.kind == TokenKind::Word && !sql_lexer::is_keyword(&tok.text))
+}
+
+fn analyze(tokens: &[Token]) -> Analysis<'_> {
+    let significant: Vec<&Token> = tokens.iter()
+        .filter(|t| t.kind != TokenKind::Whitespace && !t.is_comment())
+        .collect();
+
+    let mut scopes = vec![Scope::default()];
+    let mut stack: Vec<usize> = vec![0];
+    let mut clause_of: Vec<&'static str> = vec![""];
+    let mut open_stack: Vec<usize> = Vec::new();
+    let mut close_of = HashMap::new();
+    let mut sig: Vec<Sig> = Vec::new();
+    let mut depth = 0;
+
+    for (i, tok) in significant.iter().enumerate() {
+        let current = *stack.last().unwrap();
+        match tok.kind {
+            TokenKind::OpenParen => {
+                sig.push(Sig { tok, depth, scope: current, clause: clause_of[current] });
+                open_stack.push(i);
+                depth += 1;
+                let starts_query = significant.get(i + 1).is_some_and(|n| n.is_word("SELECT") || n.is_word("WITH"));
+                if starts_query {
+                    scopes.push(Scope { depth, ..Default::default() });
+                    clause_of.push("");
+                    stack.push(scopes.len() - 1);
+                } else {
+                    stack.push(current);
+                }
+                continue;
+            }
+            TokenKind::CloseParen if stack.len() > 1 => {
+                stack.pop();
+                depth -= 1;
+                if let Some(open) = open_stack.pop() {
+                    close_of.insert(open, i);
+                }
+                let outer = *stack.last().unwrap();
+                sig.push(Sig { tok, depth, scope: outer, clause: clause_of[outer] });
+                continue;
+            }
+            _ => {}
+        }
+
+        if tok.kind == TokenKind::Word && depth == scopes[current].depth {
+            let upper = tok.upper();
+            if matches!(upper.as_str(), "UNION" | "EXCEPT" | "INTERSECT") {
+                // Each branch of a set operation resolves its own tables
+                scopes.push(Scope { depth, ..Default::default() });
+                clause_of.push("UNION");
+                *stack.last_mut().unwrap() = scopes.len() - 1;
+            } else if let Some(w) = CLAUSE_WORDS.iter().find(|w| **w == upper) {
+                clause_of[current] = if *w == "STRAIGHT_JOIN" { "JOIN" } else { w };
+                if *w == "WHERE" {
+                    scopes[current].has_where = true;
+                }
+            }
+        }
+
+        let current = *stack.last().unwrap();
+        sig.push(Sig { tok, depth, scope: current, clause: clause_of[current] });
+    }
+
+    let mut analysis = Analysis { sig, close_of, scopes, columns: Vec::new() };
+    let consumed = collect_tables(&mut analysis);
+    collect_columns(&mut analysis, &consumed);
+    analysis
+}
+
+/// Records table references per scope and returns the token positions they occupy.
+fn collect_tables(a: &mut Analysis) -> HashSet<usize> {
+    let mut consumed: HashSet<usize> = HashSet::new();
+    let mut in_from: HashMap<usize, bool> = HashMap::new();
+    let len = a.sig.len();
+
+    for i in 0..len {
+        let (scope, depth) = (a.sig[i].scope, a.sig[i].depth);
+        if depth != a.scopes[scope].depth || consumed.contains(&i) {
+            continue;
+        }
+        let tok = a.sig[i].tok;
+        let upper = if tok.kind == TokenKind::Word { tok.upper() } else { String::new() };
+
+        let parse_at = match upper.as_str() {
+            "FROM" | "JOIN" | "STRAIGHT_JOIN" => {
+                in_from.insert(scope, true);
+                Some(i + 1)
+            }
+            "UPDATE" if i == 0 => {
+                in_from.insert(scope, true);
+                let mut j = i + 1;
+                while j < len && (a.sig[j].tok.is_word("LOW_PRIORITY") || a.sig[j].tok.is_word("IGNORE")) {
+                    j += 1;
+                }
+                Some(j)
+            }
+            "NATURAL" => {
+                a.scopes[scope].natural = true;
+                None
+            }
+            "USING" => {
+                // JOIN ... USING (a, b): those columns are merged and never ambiguous
+                if let Some(&close) = a.close_of.get(&(i + 1)) {
+                    for k in i + 2..close {
+                        if is_name(a.sig[k].tok) {
+                            let name = unquote(&a.sig[k].tok.text).to_lowercase();
+                            a.scopes[scope].using_cols.insert(name);
+                            consumed.insert(k);
+                        }
+                    }
+                }
+                in_from.insert(scope, false);
+                None
+            }
+            "WHERE" | "GROUP" | "ORDER" | "HAVING" | "LIMIT" | "ON" | "SET" | "WINDOW" | "INTO" | "UNION" => {
+                in_from.insert(scope, false);
+                None
+            }
+            _ if tok.kind == TokenKind::Comma && in_from.get(&scope).copied().unwrap_or(false) => Some(i + 1),
+            _ => None,
+        };
+
+        let Some(j) = parse_at else { continue };
+        if j >= len {
+            continue;
+        }
+        if a.sig[j].tok.kind == TokenKind::OpenParen {
+            // Derived table: its columns are unknown here, but the alias after it still names a source
+            if let Some(&close) = a.close_of.get(&j) {
+                let mut k = close + 1;
+                if k < len && a.sig[k].tok.is_word("AS") {
+                    k += 1;
+                }
+                if k < len && is_name(a.sig[k].tok) {
+                    a.scopes[scope].tables.push(TableRef { db: None, name: unquote(&a.sig[k].tok.text), alias: None });
+                    consumed.insert(k);
+                }
+            }
+            continue;
+        }
+        if !is_name(a.sig[j].tok) {
+            continue;
+        }
+
+        let mut db = None;
+        let mut name = unquote(&a.sig[j].tok.text);
+        consumed.insert(j);
+        let mut k = j + 1;
+        if k + 1 < len && a.sig[k].tok.kind == TokenKind::Dot && is_name(a.sig[k + 1].tok) {
+            db = Some(name);
+            name = unquote(&a.sig[k + 1].tok.text);
+            consumed.insert(k + 1);
+            k += 2;
+        }
+        let mut alias = None;
+        if k < len && a.sig[k].tok.is_word("AS") {
+            k += 1;
+        }
+        if k < len && is_name(a.sig[k].tok) {
+            alias = Some(unquote(&a.sig[k].tok.text));
+            consumed.insert(k);
+        }
+        a.scopes[scope].tables.push(TableRef { db, name, alias });
+    }
+
+    consumed
+}
+
+fn collect_columns(a: &mut Analysis, consumed: &HashSet<usize>) {
+    let len = a.sig.len();
+    let mut i = 0;
+
+    while i < len {
+        let tok = a.sig[i].tok;
+        if consumed.contains(&i) || !is_name(tok) {
+            i += 1;
+            continue;
+        }
+        let next = a.sig.get(i + 1).map(|s| s.tok);
+        let prev = if i > 0 { Some(a.sig[i - 1].tok) } else { None };
+
+        if next.is_some_and(|n| n.kind == TokenKind::OpenParen) || prev.is_some_and(|p| p.kind == TokenKind::Dot) {
+            i += 1;
+            continue;
+        }
+
+        if prev.is_some_and(|p| p.is_word("AS")) {
+            let scope = a.sig[i].scope;
+            a.scopes[scope].aliases.insert(unquote(&tok.text).to_lowercase());
+            i += 1;
+            continue;
+        }
+
+        // qualifier.column or schema.table.column
+        if next.is_some_and(|n| n.kind == TokenKind::Dot) {
+            let mut q = i;
+            let mut c = i + 2;
+            if c + 1 < len && a.sig[c + 1].tok.kind == TokenKind::Dot {
+                q = c;
+                c += 2;
+            }
+            if c < len && is_name(a.sig[c].tok) {
+                a.columns.push(ColumnRef {
+                    idx: c,
+                    qualifier: Some(unquote(&a.sig[q].tok.text)),
+                    name: unquote(&a.sig[c].tok.text),
+                });
+            }
+            i = c + 1;
+            continue;
+        }
+
+        let leads = match prev {
+            None => false,
+            Some(p) => matches!(p.kind, TokenKind::Operator | TokenKind::Comma | TokenKind::OpenParen)
+                || (p.kind == TokenKind::Word && EXPRESSION_LEADERS.contains(&p.upper().as_str())),
+        };
+        if leads {
+            a.columns.push(ColumnRef { idx: i, qualifier: None, name: unquote(&tok.text) });
+        } else if a.sig[i].clause == "SELECT" {
+            // `expr alias` without AS
+            let scope = a.sig[i].scope;
+            a.scopes[scope].aliases.insert(unquote(&tok.text).to_lowercase());
+        }
+        i += 1;
+    }
+}
+
+struct Linter<'a> {
+    sql: &'a str,
+    schema: &'a SchemaInfo,
+    default_db: Option<&'a str>,
+    out: Vec<LintWarning>,
+}
+
+impl<'a> Linter<'a> {
+    fn push(&mut self, rule: &str, severity: &str, message: String, start: usize, end: usize) {
+        let (line, column) = sql_lexer::line_col(self.sql, start);
+        let (end_line, end_column) = sql_lexer::line_col(self.sql, end);
+        self.out.push(LintWarning {
+            rule: rule.to_string(),
+            severity: severity.to_string(),
+            message,
+            start,
+            end,
+            line,
+            column,
+            end_line,
+            end_column,
+        });
+    }
+
+    /// Tables of a scope that contain `column`, honouring an optional qualifier.
+    fn owners(&self, scope: &Scope, qualifier: Option<&str>, column: &str) -> Vec<(String, TableKey)> {
+        scope.tables.iter()
+            .filter(|t| qualifier.map_or(true, |q| t.matches(q)))
+            .filter_map(|t| t.key(self.default_db).map(|k| (t.alias.clone().unwrap_or_else(|| t.name.clone()), k)))
+            .filter(|(_, k)| self.schema.column(k, column).is_some())
+            .collect()
+    }
+
+    fn lint_statement(&mut self, a: &Analysis) {
+        let sig = &a.sig;
+        if sig.is_empty() {
+            return;
+        }
+        let first = sig[0].tok;
+        let first_word = first.upper();
+
+        // UPDATE / DELETE without WHERE
+        if first.kind == TokenKind::Word && (first_word == "UPDATE" || first_word == "DELETE") && !a.scopes[0].has_where {
+            self.push(
+                "update_delete_without_where",
+                "warning",
+                format!("{} without a WHERE clause affects every row in the table", first_word),
+                first.start,
+                first.end,
+            );
+        }
+
+        let is_view = first_word == "CREATE" && sig.iter().take_while(|s| !s.tok.is_word("AS")).any(|s| s.tok.is_word("VIEW"));
+
+        for (i, s) in sig.iter().enumerate() {
+            let tok = s.tok;
+            let prev = if i > 0 { Some(sig[i - 1].tok) } else { None };
+            let next = sig.get(i + 1).map(|n| n.tok);
+
+            // SELECT * inside a view definition
+            if is_view && tok.kind == TokenKind::Operator && tok.text == "*" && s.clause == "SELECT" {
+                let is_star = prev.is_some_and(|p| {
+                    matches!(p.kind, TokenKind::Comma | TokenKind::Dot)
+                        || p.is_word("SELECT") || p.is_word("DISTINCT") || p.is_word("ALL")
+                });
+                if is_star {
+                    self.push(
+                        "select_star_in_view",
+                        "warning",
+                        "SELECT * in a view is expanded when the view is created; columns added later will not appear. List the columns explicitly".to_string(),
+                        tok.start,
+                        tok.end,
+                    );
+                }
+            }
+
+            // ORDER BY RAND()
+            if tok.is_word("RAND") && s.clause == "ORDER" && next.is_some_and(|n| n.kind == TokenKind::OpenParen) {
+                let end = a.close_of.get(&(i + 1)).map(|&c| sig[c].tok.end).unwrap_or(tok.end);
+                self.push(
+                    "order_by_rand",
+                    "warning",
+                    "ORDER BY RAND() sorts the entire result set; on large tables pick random keys instead".to_string(),
+                    tok.start,
+                    end,
+                );
+            }
+
+            // LIKE '%...'
+            if tok.is_word("LIKE") {
+                if let Some(n) = next.filter(|n| n.kind == TokenKind::String) {
+                    let body = n.text.find(['\'', '"']).map(|q| &n.text[q + 1..]).unwrap_or("");
+                    if body.starts_with('%') || body.starts_with('_') {
+                        self.push(
+                            "leading_wildcard_like",
+                            "info",
+                            "A leading wildcard in LIKE cannot use an index and scans every row; consider a FULLTEXT index".to_string(),
+                            n.start,
+                            n.end,
+                        );
+                    }
+                }
+            }
+
+            // NOT IN (SELECT ...)
+            if tok.is_word("NOT")
+                && next.is_some_and(|n| n.is_word("IN"))
+                && sig.get(i + 2).is_some_and(|n| n.tok.kind == TokenKind::OpenParen)
+                && sig.get(i + 3).is_some_and(|n| n.tok.is_word("SELECT"))
+            {
+                self.check_not_in(a, i);
+            }
+
+            // JOIN without ON / USING
+            if tok.is_word("JOIN") && s.depth == a.scopes[s.scope].depth {
+                let explicit_cross = prev.is_some_and(|p| p.is_word("CROSS") || p.is_word("NATURAL"));
+                let has_condition = sig[i + 1..].iter()
+                    .filter(|n| n.scope == s.scope && n.depth == s.depth)
+                    .take_while(|n| !(n.tok.is_word("JOIN") || n.tok.kind == TokenKind::Comma || ["WHERE", "GROUP", "ORDER", "HAVING", "LIMIT", "UNION"].iter().any(|w| n.tok.is_word(w))))
+                    .any(|n| n.tok.is_word("ON") || n.tok.is_word("USING"));
+                if !explicit_cross && !has_condition {
+                    self.push(
+                        "implicit_cross_join",
+                        "warning",
+                        "JOIN without ON or USING produces a cross join (every row combined with every row)".to_string(),
+                        tok.start,
+                        tok.end,
+                    );
+                }
+            }
+
+            // FROM a, b
+            if tok.kind == TokenKind::Comma && s.clause == "FROM" && s.depth == a.scopes[s.scope].depth {
+                let scope = &a.scopes[s.scope];
+                let (severity, message) = if scope.has_where {
+                    ("info", "Comma-separated tables form an implicit join; prefer an explicit JOIN ... ON so the join condition cannot be forgotten")
+                } else {
+                    ("warning", "Comma-separated tables without a WHERE clause produce a cross join (Cartesian product)")
+                };
+                self.push("implicit_cross_join", severity, message.to_string(), tok.start, tok.end);
+            }
+
+            // Functions wrapped around indexed columns in predicates
+            if matches!(s.clause, "WHERE" | "ON" | "HAVING")
+                && tok.kind == TokenKind::Word
+                && next.is_some_and(|n| n.kind == TokenKind::OpenParen)
+                && !sql_lexer::is_keyword(&tok.text)
+            {
+                self.check_function_on_index(a, i);
+            }
+        }
+
+        self.check_ambiguous(a);
+    }
+
+    fn check_not_in(&mut self, a: &Analysis, not_idx: usize) {
+        let sig = &a.sig;
+        let select_idx = not_idx + 3;
+        let inner = sig[select_idx].scope;
+        let close = a.close_of.get(&(not_idx + 2)).copied().unwrap_or(sig.len() - 1);
+
+        // First select-list item, when it is a plain column reference
+        let item: Vec<usize> = (select_idx + 1..close)
+            .filter(|&k| sig[k].scope == inner)
+            .take_while(|&k| !(sig[k].tok.kind == TokenKind::Comma || sig[k].tok.is_word("FROM")))
+            .filter(|&k| !sig[k].tok.is_word("DISTINCT"))
+            .collect();
+        let column = a.columns.iter().find(|c| item.contains(&c.idx) && item.last() == Some(&c.idx) && item.len() <= 3);
+
+        let nullable = column.and_then(|c| {
+            let owners = self.owners(&a.scopes[inner], c.qualifier.as_deref(), &c.name);
+            owners.first().and_then(|(_, key)| self.schema.column(key, &c.name))
+        });
+
+        let (start, end) = (sig[not_idx].tok.start, sig[not_idx + 1].tok.end);
+        match nullable {
+            Some(false) => {}
+            Some(true) => self.push(
+                "not_in_nullable_subquery",
+                "warning",
+                format!(
+                    "Subquery column `{}` is nullable; a single NULL makes NOT IN return no rows. Use NOT EXISTS instead",
+                    column.map(|c| c.name.as_str()).unwrap_or_default()
+                ),
+                start,
+                end,
+            ),
+            None => self.push(
+                "not_in_nullable_subquery",
+                "info",
+                "NOT IN with a subquery returns no rows if the subquery yields any NULL; NOT EXISTS is safer".to_string(),
+                start,
+                end,
+            ),
+        }
+    }
+
+    fn check_function_on_index(&mut self, a: &Analysis, fn_idx: usize) {
+        let sig = &a.sig;
+        let Some(&close) = a.close_of.get(&(fn_idx + 1)) else { return };
+        let compared_after = sig.get(close + 1).is_some_and(|n| {
+            (n.tok.kind == TokenKind::Operator && COMPARISONS.contains(&n.tok.text.as_str()))
+                || ["LIKE", "IN", "BETWEEN", "IS"].iter().any(|w| n.tok.is_word(w))
+        });
+        let compared_before = fn_idx > 0
+            && sig[fn_idx - 1].tok.kind == TokenKind::Operator
+            && COMPARISONS.contains(&sig[fn_idx - 1].tok.text.as_str());
+        if !compared_after && !compared_before {
+            return;
+        }
+
+        let scope = &a.scopes[sig[fn_idx].scope];
+        for col in a.columns.iter().filter(|c| c.idx > fn_idx + 1 && c.idx < close) {
+            for (_, key) in self.owners(scope, col.qualifier.as_deref(), &col.name) {
+                if let Some(index) = self.schema.index_on(&key, &col.name) {
+                    let call = &self.sql[sig[fn_idx].tok.start..sig[close].tok.end];
+                    self.push(
+                        "function_on_indexed_column",
+                        "warning",
+                        format!(
+                            "`{}` wraps indexed column `{}`, so index `{}` cannot be used; compare the bare column against a range instead",
+                            call, col.name, index
+                        ),
+                        sig[fn_idx].tok.start,
+                        sig[close].tok.end,
+                    );
+                    return;
+                }
+            }
+        }
+    }
+
+    fn check_ambiguous(&mut self, a: &Analysis) {
+        for col in a.columns.iter().filter(|c| c.qualifier.is_none()) {
+            let s = &a.sig[col.idx];
+            let scope = &a.scopes[s.scope];
+            let lower = col.name.to_lowercase();
+            if scope.tables.len() < 2 || scope.natural || scope.using_cols.contains(&lower) || scope.aliases.contains(&lower) {
+                continue;
+            }
+            let owners = self.owners(scope, None, &col.name);
+            if owners.len() > 1 {
+                let names = owners.iter().map(|(n, _)| format!("`{}`", n)).collect::<Vec<_>>().join(", ");
+                self.push(
+                    "ambiguous_column",
+                    "error",
+                    format!("Column `{}` is ambiguous: it exists in {}. Qualify it with a table name or alias", col.name, names),
+                    s.tok.start,
+                    s.tok.end,
+                );
+            }
+        }
+    }
+}
+
+fn lint_with_schema(sql: &str, statements: &[Analysis], schema: &SchemaInfo, default_db: Option<&str>) -> Vec<LintWarning> {
+    let mut linter = Linter { sql, schema, default_db, out: Vec::new() };
+    for analysis in statements {
+        linter.lint_statement(analysis);
+    }
+    linter.out.sort_by_key(|w| w.start);
+    linter.out
+}
+
+#[tauri::command]
+pub async fn lint_sql(sql: String, db: Option<String>, state: State<'_, AppState>) -> Result<Vec<LintWarning>, String> {
+    let tokens = sql_lexer::tokenize(&sql);
+    let statements: Vec<Vec<Token>> = sql_lexer::split_statements(&tokens);
+    let analyses: Vec<Analysis> = statements.iter().map(|s| analyze(s)).collect();
+    let default_db = db.filter(|d| !d.is_empty());
+
+    let pool_opt = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned()
+    };
+
+    // Schema-aware rules (nullability, indexes, ambiguity) only run when connected
+    let mut schema = SchemaInfo::default();
+    if let Some(pool) = pool_opt {
+        // Names are queried as written, since table names are case sensitive on most
+        // platforms; only the map key is lowercased.
+        let mut tables: Vec<(String, String)> = analyses.iter()
+            .flat_map(|a| a.scopes.iter().flat_map(|s| s.tables.iter()))
+            .filter_map(|t| t.qualified(default_db.as_deref()))
+            .collect();
+        tables.sort();
+        tables.dedup();
+
+        if !tables.is_empty() {
+            let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+            for (db, table) in tables {
+                let key = (db.to_lowercase(), table.to_lowercase());
+                if schema.columns.contains_key(&key) {
+                    continue;
+                }
+                let cols: Vec<(String, String)> = conn.exec(
+                    "SELECT COLUMN_NAME, IS_NULLABLE FROM information_schema.COLUMNS WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ?",
+                    (&db, &table),
+                ).await.map_err(|e| e.to_string())?;
+                if cols.is_empty() {
+                    continue;
+                }
+                let indexes: Vec<(String, String)> = conn.exec(
+                    "SELECT COLUMN_NAME, INDEX_NAME FROM information_schema.STATISTICS
+                     WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ? AND SEQ_IN_INDEX = 1 AND COLUMN_NAME IS NOT NULL",
+                    (&db, &table),
+                ).await.map_err(|e| e.to_string())?;
+
+                schema.columns.insert(key.clone(), cols.into_iter().map(|(c, n)| (c.to_lowercase(), n == "YES")).collect());
+                schema.indexed.insert(key, indexes.into_iter().map(|(c, i)| (c.to_lowercase(), i)).collect());
+            }
+        }
+    }
+
+    Ok(lint_with_schema(&sql, &analyses, &schema, default_db.as_deref()))
+}
@@ -13,3 +13,21 @@ pub mod routines;
 pub mod snippets;
 pub mod ai;
 pub mod preferences;
+pub mod sql_lexer;
+pub mod formatter;
+pub mod lint;
+pub mod compare;
+pub mod notebooks;
+pub mod rows;
+pub mod values;
+pub mod cells;
+pub mod columns;
+pub mod bulk;
+pub mod views;
+pub mod ddl;
+pub mod comments;
+pub mod constraints;
+pub mod partitions;
+pub mod profiling;
+pub mod auto_increment;
+pub mod type_change;
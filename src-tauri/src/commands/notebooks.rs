@@ -0,0 +1,299 @@
+use tauri::State;
+use crate::state::AppState;
+use crate::commands::common::mysql_to_json;
+use mysql_async::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+const DEFAULT_MAX_SAVED_ROWS: usize = 500;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CellOutput {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<serde_json::Value>>,
+    pub total_rows: usize,
+    pub truncated: bool,
+    pub error: Option<String>,
+    pub executed_at: String,
+    pub duration_ms: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NotebookCell {
+    pub id: String,
+    pub cell_type: String, // "sql" or "markdown"
+    pub source: String,
+    #[serde(default)]
+    pub output: Option<CellOutput>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Notebook {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub server_id: Option<String>,
+    pub database: Option<String>,
+    pub cells: Vec<NotebookCell>,
+    pub created_at: String,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct NotebookSummary {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub server_id: Option<String>,
+    pub database: Option<String>,
+    pub cell_count: usize,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+pub struct NotebookRunOptions {
+    pub max_rows: Option<usize>,
+    pub stop_on_error: Option<bool>,
+    pub from_cell: Option<String>, // run from this cell to the end
+}
+
+fn get_notebooks_dir() -> PathBuf {
+    // One file per notebook, next to snippets.json, so notebooks can be shared individually.
+    PathBuf::from("app_data/notebooks")
+}
+
+fn ensure_notebooks_dir() {
+    let path = get_notebooks_dir();
+    if !path.exists() {
+        let _ = fs::create_dir_all(path);
+    }
+}
+
+fn notebook_path(id: &str) -> Result<PathBuf, String> {
+    if id.is_empty() || !id.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_') {
+        return Err(format!("Invalid notebook id: {}", id));
+    }
+    Ok(get_notebooks_dir().join(format!("{}.json", id)))
+}
+
+fn load_notebook(id: &str) -> Result<Notebook, String> {
+    let path = notebook_path(id)?;
+    if !path.exists() {
+        return Err(format!("Notebook not found: {}", id));
+    }
+    let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&content).map_err(|e| e.to_string())
+}
+
+fn write_notebook(notebook: &Notebook) -> Result<(), String> {
+    ensure_notebooks_dir();
+    let json = serde_json::to_string_pretty(notebook).map_err(|e| e.to_string())?;
+    fs::write(notebook_path(&notebook.id)?, json).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_notebooks() -> Result<Vec<NotebookSummary>, String> {
+    ensure_notebooks_dir();
+    let mut notebooks = Vec::new();
+    for entry in fs::read_dir(get_notebooks_dir()).map_err(|e| e.to_string())? {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        if path.extension().is_some_and(|e| e == "json") {
+            let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+            if let Ok(nb) = serde_json::from_str::<Notebook>(&content) {
+                notebooks.push(NotebookSummary {
+                    id: nb.id,
+                    name: nb.name,
+                    description: nb.description,
+                    server_id: nb.server_id,
+                    database: nb.database,
+                    cell_count: nb.cells.len(),
+                    created_at: nb.created_at,
+                    updated_at: nb.updated_at,
+                });
+            }
+        }
+    }
+    notebooks.sort_by_key(|n| n.name.to_lowercase());
+    Ok(notebooks)
+}
+
+#[tauri::command]
+pub async fn get_notebook(id: String) -> Result<Notebook, String> {
+    load_notebook(&id)
+}
+
+#[tauri::command]
+pub async fn save_notebook(mut notebook: Notebook) -> Result<Notebook, String> {
+    for cell in &notebook.cells {
+        if cell.cell_type != "sql" && cell.cell_type != "markdown" {
+            return Err(format!("Unknown cell type: {}", cell.cell_type));
+        }
+    }
+    notebook.updated_at = Some(chrono::Local::now().to_rfc3339());
+    write_notebook(&notebook)?;
+    Ok(notebook)
+}
+
+#[tauri::command]
+pub async fn delete_notebook(id: String) -> Result<(), String> {
+    let path = notebook_path(&id)?;
+    if path.exists() {
+        fs::remove_file(path).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Connection for running a notebook. Cells share it, so session state such as variables,
+/// temporary tables and `USE` carries over from one cell to the next. Notebooks saved for a
+/// server only run while that server is the active connection.
+async fn notebook_conn(notebook: &Notebook, state: &State<'_, AppState>) -> Result<mysql_async::Conn, String> {
+    if let Some(expected) = &notebook.server_id {
+        let active = state.server_id.lock().unwrap().clone();
+        if active.as_ref() != Some(expected) {
+            return Err(format!("Notebook \"{}\" belongs to server {}; connect to it before running", notebook.name, expected));
+        }
+    }
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+    if let Some(db) = notebook.database.as_deref().filter(|d| !d.is_empty()) {
+        conn.query_drop(format!("USE `{}`", db)).await.map_err(|e| e.to_string())?;
+    }
+    Ok(conn)
+}
+
+async fn query_rows(conn: &mut mysql_async::Conn, sql: &str) -> Result<(Vec<String>, Vec<Vec<serde_json::Value>>), String> {
+    let mut result = conn.query_iter(sql).await.map_err(|e| format!("SQL Error: {}", e))?;
+    let columns: Vec<String> = result
+        .columns()
+        .map(|cols| cols.iter().map(|c| c.name_str().into_owned()).collect())
+        .unwrap_or_default();
+    let rows_data: Vec<mysql_async::Row> = result.collect().await.map_err(|e| e.to_string())?;
+    let rows = rows_data
+        .into_iter()
+        .map(|row| (0..columns.len()).map(|i| mysql_to_json(row.get(i).unwrap_or(mysql_async::Value::NULL))).collect())
+        .collect();
+    Ok((columns, rows))
+}
+
+async fn run_cell(conn: &mut mysql_async::Conn, sql: &str, max_rows: usize) -> CellOutput {
+    let start = std::time::Instant::now();
+    let executed_at = chrono::Local::now().to_rfc3339();
+    match query_rows(conn, sql).await {
+        Ok((columns, mut rows)) => {
+            let total_rows = rows.len();
+            rows.truncate(max_rows);
+            CellOutput {
+                columns,
+                truncated: total_rows > rows.len(),
+                rows,
+                total_rows,
+                error: None,
+                executed_at,
+                duration_ms: start.elapsed().as_secs_f64() * 1000.0,
+            }
+        }
+        Err(e) => CellOutput {
+            columns: Vec::new(),
+            rows: Vec::new(),
+            total_rows: 0,
+            truncated: false,
+            error: Some(e),
+            executed_at,
+            duration_ms: start.elapsed().as_secs_f64() * 1000.0,
+        },
+    }
+}
+
+/// Saves a cell output into the latest copy on disk, so edits made while a query ran are kept.
+fn store_output(notebook_id: &str, cell_id: &str, output: CellOutput) -> Result<Notebook, String> {
+    let mut notebook = load_notebook(notebook_id)?;
+    if let Some(cell) = notebook.cells.iter_mut().find(|c| c.id == cell_id) {
+        cell.output = Some(output);
+    }
+    write_notebook(&notebook)?;
+    Ok(notebook)
+}
+
+/// Executes one SQL cell against the notebook's database and saves its (capped) result.
+#[tauri::command]
+pub async fn run_notebook_cell(notebook_id: String, cell_id: String, max_rows: Option<usize>, state: State<'_, AppState>) -> Result<NotebookCell, String> {
+    let notebook = load_notebook(&notebook_id)?;
+    let cell = notebook.cells.iter().find(|c| c.id == cell_id).ok_or(format!("Cell not found: {}", cell_id))?;
+    if cell.cell_type != "sql" {
+        return Err("Only SQL cells can be executed".to_string());
+    }
+
+    let mut conn = notebook_conn(&notebook, &state).await?;
+    let output = run_cell(&mut conn, &cell.source, max_rows.unwrap_or(DEFAULT_MAX_SAVED_ROWS)).await;
+    let notebook = store_output(&notebook_id, &cell_id, output)?;
+
+    notebook.cells.into_iter().find(|c| c.id == cell_id).ok_or(format!("Cell not found: {}", cell_id))
+}
+
+/// Executes SQL cells in order on one connection, saving each output as it completes.
+#[tauri::command]
+pub async fn run_notebook(notebook_id: String, options: Option<NotebookRunOptions>, state: State<'_, AppState>) -> Result<Notebook, String> {
+    let opts = options.unwrap_or_default();
+    let max_rows = opts.max_rows.unwrap_or(DEFAULT_MAX_SAVED_ROWS);
+    let stop_on_error = opts.stop_on_error.unwrap_or(true);
+
+    let mut notebook = load_notebook(&notebook_id)?;
+    let start = match &opts.from_cell {
+        Some(id) => notebook.cells.iter().position(|c| &c.id == id).ok_or(format!("Cell not found: {}", id))?,
+        None => 0,
+    };
+
+    let mut conn = notebook_conn(&notebook, &state).await?;
+    let cells: Vec<NotebookCell> = notebook.cells.drain(start..).collect();
+    for cell in cells {
+        if cell.cell_type != "sql" || cell.source.trim().is_empty() {
+            continue;
+        }
+        let output = run_cell(&mut conn, &cell.source, max_rows).await;
+        let failed = output.error.is_some();
+        store_output(&notebook_id, &cell.id, output)?;
+        if failed && stop_on_error {
+            break;
+        }
+    }
+
+    load_notebook(&notebook_id)
+}
+
+#[tauri::command]
+pub async fn clear_notebook_outputs(notebook_id: String) -> Result<Notebook, String> {
+    let mut notebook = load_notebook(&notebook_id)?;
+    for cell in notebook.cells.iter_mut() {
+        cell.output = None;
+    }
+    write_notebook(&notebook)?;
+    Ok(notebook)
+}
+
+#[tauri::command]
+pub async fn export_notebook(id: String, file_path: String, include_outputs: Option<bool>) -> Result<(), String> {
+    let mut notebook = load_notebook(&id)?;
+    if !include_outputs.unwrap_or(true) {
+        for cell in notebook.cells.iter_mut() {
+            cell.output = None;
+        }
+    }
+    let json = serde_json::to_string_pretty(&notebook).map_err(|e| e.to_string())?;
+    fs::write(file_path, json).map_err(|e| e.to_string())
+}
+
+/// Imports a notebook file. An existing notebook with the same id is replaced.
+#[tauri::command]
+pub async fn import_notebook(file_path: String) -> Result<Notebook, String> {
+    let content = fs::read_to_string(&file_path).map_err(|e| e.to_string())?;
+    let notebook: Notebook = serde_json::from_str(&content).map_err(|e| format!("Invalid notebook file: {}", e))?;
+    notebook_path(&notebook.id)?;
+    write_notebook(&notebook)?;
+    Ok(notebook)
+}
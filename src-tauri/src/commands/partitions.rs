@@ -0,0 +1,301 @@
+use tauri::State;
+use crate::state::AppState;
+use crate::commands::common::{quote_identifier, SqlMode};
+use mysql_async::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize)]
+pub struct SubpartitionInfo {
+    pub name: String,
+    pub rows: u64,
+    pub data_length: u64,
+    pub index_length: u64,
+}
+
+#[derive(Serialize)]
+pub struct PartitionInfo {
+    pub name: String,
+    pub ordinal: u64,
+    pub description: Option<String>, // boundary: LESS THAN value or IN list
+    pub rows: u64,
+    pub data_length: u64,
+    pub index_length: u64,
+    pub data_free: u64,
+    pub comment: String,
+    pub subpartitions: Vec<SubpartitionInfo>,
+}
+
+#[derive(Serialize)]
+pub struct TablePartitions {
+    pub is_partitioned: bool,
+    pub method: Option<String>, // RANGE, LIST, HASH, KEY, RANGE COLUMNS, ...
+    pub expression: Option<String>,
+    pub subpartition_method: Option<String>,
+    pub subpartition_expression: Option<String>,
+    pub partitions: Vec<PartitionInfo>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct PartitionDefinition {
+    pub name: String,
+    pub less_than: Option<String>, // RANGE: value list or MAXVALUE
+    pub values_in: Option<String>, // LIST: comma separated values
+    pub comment: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct PartitionScheme {
+    pub method: String,     // RANGE, RANGE COLUMNS, LIST, LIST COLUMNS, HASH, LINEAR HASH, KEY or LINEAR KEY
+    pub expression: String, // expression, or column list for COLUMNS / KEY
+    #[serde(default)]
+    pub partitions: Vec<PartitionDefinition>,
+    pub count: Option<u32>, // HASH / KEY without explicit partitions
+}
+
+const METHODS: &[&str] = &["RANGE", "RANGE COLUMNS", "LIST", "LIST COLUMNS", "HASH", "LINEAR HASH", "KEY", "LINEAR KEY"];
+
+#[tauri::command]
+pub async fn get_partitions(db: String, table: String, state: State<'_, AppState>) -> Result<TablePartitions, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let rows: Vec<mysql_async::Row> = conn
+        .exec(
+            "SELECT PARTITION_NAME, SUBPARTITION_NAME, PARTITION_ORDINAL_POSITION, PARTITION_METHOD, PARTITION_EXPRESSION,
+                    SUBPARTITION_METHOD, SUBPARTITION_EXPRESSION, PARTITION_DESCRIPTION, TABLE_ROWS,
+                    DATA_LENGTH, INDEX_LENGTH, DATA_FREE, PARTITION_COMMENT
+             FROM information_schema.PARTITIONS
+             WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ?
+             ORDER BY PARTITION_ORDINAL_POSITION, SUBPARTITION_ORDINAL_POSITION",
+            (&db, &table),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let text = |row: &mysql_async::Row, i: usize| row.get::<Option<String>, _>(i).flatten();
+    let num = |row: &mysql_async::Row, i: usize| row.get::<Option<u64>, _>(i).flatten().unwrap_or(0);
+
+    let mut result = TablePartitions {
+        is_partitioned: false,
+        method: None,
+        expression: None,
+        subpartition_method: None,
+        subpartition_expression: None,
+        partitions: Vec::new(),
+    };
+    for row in &rows {
+        // Unpartitioned tables have a single row with a NULL partition name.
+        let Some(name) = text(row, 0) else { continue };
+        result.is_partitioned = true;
+        result.method = text(row, 3);
+        result.expression = text(row, 4);
+        result.subpartition_method = text(row, 5);
+        result.subpartition_expression = text(row, 6);
+
+        if result.partitions.last().map(|p| &p.name) != Some(&name) {
+            result.partitions.push(PartitionInfo {
+                name,
+                ordinal: num(row, 2),
+                description: text(row, 7),
+                rows: 0,
+                data_length: 0,
+                index_length: 0,
+                data_free: 0,
+                comment: text(row, 12).unwrap_or_default(),
+                subpartitions: Vec::new(),
+            });
+        }
+        let partition = result.partitions.last_mut().unwrap();
+        partition.rows += num(row, 8);
+        partition.data_length += num(row, 9);
+        partition.index_length += num(row, 10);
+        partition.data_free += num(row, 11);
+        if let Some(sub) = text(row, 1) {
+            partition.subpartitions.push(SubpartitionInfo {
+                name: sub,
+                rows: num(row, 8),
+                data_length: num(row, 9),
+                index_length: num(row, 10),
+            });
+        }
+    }
+    Ok(result)
+}
+
+fn partition_sql(def: &PartitionDefinition, mode: SqlMode) -> Result<String, String> {
+    if def.name.trim().is_empty() {
+        return Err("Partition name is required".to_string());
+    }
+    let mut sql = format!("PARTITION {}", quote_identifier(&def.name));
+    match (def.less_than.as_deref().map(str::trim), def.values_in.as_deref().map(str::trim)) {
+        (Some(v), None) if v.eq_ignore_ascii_case("MAXVALUE") => sql.push_str(" VALUES LESS THAN MAXVALUE"),
+        (Some(v), None) if !v.is_empty() => sql.push_str(&format!(" VALUES LESS THAN ({})", v)),
+        (None, Some(v)) if !v.is_empty() => sql.push_str(&format!(" VALUES IN ({})", v)),
+        (None, None) => {}
+        _ => return Err(format!("Partition `{}`: give either a LESS THAN or an IN value list", def.name)),
+    }
+    if let Some(comment) = def.comment.as_deref().filter(|c| !c.is_empty()) {
+        sql.push_str(&format!(" COMMENT = {}", mode.quote(comment)));
+    }
+    Ok(sql)
+}
+
+fn partition_list(defs: &[PartitionDefinition], mode: SqlMode) -> Result<String, String> {
+    let parts = defs.iter().map(|def| partition_sql(def, mode)).collect::<Result<Vec<_>, _>>()?;
+    Ok(format!("(\n  {}\n)", parts.join(",\n  ")))
+}
+
+fn name_list(names: &[String]) -> Result<String, String> {
+    if names.is_empty() {
+        return Err("No partitions given".to_string());
+    }
+    Ok(names.iter().map(|n| quote_identifier(n)).collect::<Vec<_>>().join(", "))
+}
+
+fn alter_prefix(db: &str, table: &str) -> String {
+    format!("ALTER TABLE {}.{}", quote_identifier(db), quote_identifier(table))
+}
+
+/// `ALTER TABLE ... PARTITION BY` for an existing (possibly unpartitioned) table.
+pub fn partition_table_sql(db: &str, table: &str, scheme: &PartitionScheme, mode: SqlMode) -> Result<String, String> {
+    let method = scheme.method.trim().to_uppercase();
+    if !METHODS.contains(&method.as_str()) {
+        return Err(format!("Invalid partitioning method: {}", scheme.method));
+    }
+    let expression = scheme.expression.trim();
+    if expression.is_empty() && !method.ends_with("KEY") {
+        return Err("Partitioning expression is required".to_string());
+    }
+    let mut sql = format!("{} PARTITION BY {} ({})", alter_prefix(db, table), method, expression);
+
+    let is_range = method.starts_with("RANGE");
+    let is_list = method.starts_with("LIST");
+    if is_range || is_list {
+        if scheme.partitions.is_empty() {
+            return Err(format!("{} partitioning needs at least one partition", method));
+        }
+        for def in &scheme.partitions {
+            if (is_range && def.less_than.is_none()) || (is_list && def.values_in.is_none()) {
+                return Err(format!("Partition `{}` needs {} values", def.name, if is_range { "LESS THAN" } else { "IN" }));
+            }
+        }
+        sql.push_str(&format!(" {}", partition_list(&scheme.partitions, mode)?));
+    } else if !scheme.partitions.is_empty() {
+        sql.push_str(&format!(" {}", partition_list(&scheme.partitions, mode)?));
+    } else {
+        sql.push_str(&format!(" PARTITIONS {}", scheme.count.unwrap_or(4).max(1)));
+    }
+    Ok(sql)
+}
+
+async fn run(state: &State<'_, AppState>, sql: String) -> Result<String, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+    conn.query_drop(&sql).await.map_err(|e| e.to_string())?;
+    Ok(sql)
+}
+
+async fn sql_mode(state: &State<'_, AppState>) -> Result<SqlMode, String> {
+    let pool = state.pool.lock().unwrap().as_ref().cloned();
+    SqlMode::of_pool(pool).await
+}
+
+#[tauri::command]
+pub async fn preview_partition_table(db: String, table: String, scheme: PartitionScheme, state: State<'_, AppState>) -> Result<String, String> {
+    partition_table_sql(&db, &table, &scheme, sql_mode(&state).await?)
+}
+
+/// Partitions (or re-partitions) a table. Returns the SQL that ran.
+#[tauri::command]
+pub async fn partition_table(db: String, table: String, scheme: PartitionScheme, state: State<'_, AppState>) -> Result<String, String> {
+    let sql = partition_table_sql(&db, &table, &scheme, sql_mode(&state).await?)?;
+    run(&state, sql).await
+}
+
+#[tauri::command]
+pub async fn remove_partitioning(db: String, table: String, state: State<'_, AppState>) -> Result<String, String> {
+    run(&state, format!("{} REMOVE PARTITIONING", alter_prefix(&db, &table))).await
+}
+
+/// Adds RANGE/LIST partitions, or `count` more partitions to a HASH/KEY table.
+#[tauri::command]
+pub async fn add_partitions(db: String, table: String, partitions: Vec<PartitionDefinition>, count: Option<u32>, state: State<'_, AppState>) -> Result<String, String> {
+    let sql = match count {
+        Some(n) if partitions.is_empty() => format!("{} ADD PARTITION PARTITIONS {}", alter_prefix(&db, &table), n),
+        _ if partitions.is_empty() => return Err("No partitions given".to_string()),
+        _ => format!("{} ADD PARTITION {}", alter_prefix(&db, &table), partition_list(&partitions, sql_mode(&state).await?)?),
+    };
+    run(&state, sql).await
+}
+
+/// Drops partitions together with their rows.
+#[tauri::command]
+pub async fn drop_partitions(db: String, table: String, names: Vec<String>, state: State<'_, AppState>) -> Result<String, String> {
+    run(&state, format!("{} DROP PARTITION {}", alter_prefix(&db, &table), name_list(&names)?)).await
+}
+
+#[tauri::command]
+pub async fn truncate_partitions(db: String, table: String, names: Vec<String>, state: State<'_, AppState>) -> Result<String, String> {
+    run(&state, format!("{} TRUNCATE PARTITION {}", alter_prefix(&db, &table), name_list(&names)?)).await
+}
+
+/// Splits or merges partitions: rows in `names` are redistributed into `into`.
+#[tauri::command]
+pub async fn reorganize_partitions(db: String, table: String, names: Vec<String>, into: Vec<PartitionDefinition>, state: State<'_, AppState>) -> Result<String, String> {
+    if into.is_empty() {
+        return Err("No target partitions given".to_string());
+    }
+    let into = partition_list(&into, sql_mode(&state).await?)?;
+    run(&state, format!("{} REORGANIZE PARTITION {} INTO {}", alter_prefix(&db, &table), name_list(&names)?, into)).await
+}
+
+/// Swaps a partition with an unpartitioned table of identical structure.
+#[tauri::command]
+pub async fn exchange_partition(
+    db: String,
+    table: String,
+    partition: String,
+    with_table: String,
+    with_db: Option<String>,
+    validate: Option<bool>,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    let other_db = with_db.filter(|d| !d.is_empty()).unwrap_or_else(|| db.clone());
+    let mut sql = format!(
+        "{} EXCHANGE PARTITION {} WITH TABLE {}.{}",
+        alter_prefix(&db, &table), quote_identifier(&partition), quote_identifier(&other_db), quote_identifier(&with_table)
+    );
+    if validate == Some(false) {
+        sql.push_str(" WITHOUT VALIDATION");
+    }
+    run(&state, sql).await
+}
+
+/// REBUILD, OPTIMIZE, ANALYZE, CHECK or REPAIR on selected partitions (`ALL` when empty).
+#[tauri::command]
+pub async fn partition_maintenance(db: String, table: String, op: String, names: Vec<String>, state: State<'_, AppState>) -> Result<Vec<Vec<String>>, String> {
+    let op_upper = op.to_uppercase();
+    if !["REBUILD", "OPTIMIZE", "ANALYZE", "CHECK", "REPAIR"].contains(&op_upper.as_str()) {
+        return Err("Invalid partition operation".to_string());
+    }
+    let targets = if names.is_empty() { "ALL".to_string() } else { name_list(&names)? };
+    let sql = format!("{} {} PARTITION {}", alter_prefix(&db, &table), op_upper, targets);
+
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+    let mut result = conn.query_iter(sql).await.map_err(|e| e.to_string())?;
+    let rows: Vec<mysql_async::Row> = result.collect().await.map_err(|e| e.to_string())?;
+    Ok(rows
+        .into_iter()
+        .map(|row| (0..row.len()).map(|i| row.get::<Option<String>, _>(i).flatten().unwrap_or_else(|| "NULL".to_string())).collect())
+        .collect())
+}
@@ -0,0 +1,353 @@
+use tauri::State;
+use crate::state::AppState;
+use crate::commands::common::{mysql_to_json, quote_identifier};
+use crate::commands::values::{load_column_meta, ColumnMeta};
+use base64::Engine;
+use mysql_async::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Default)]
+pub struct ProfileOptions {
+    pub columns: Option<Vec<String>>, // all columns when omitted
+    pub sample_rows: Option<u64>,     // profile only the first N rows
+    pub exact_distinct: Option<bool>, // default: exact up to EXACT_DISTINCT_LIMIT rows
+    pub top_n: Option<u32>,
+    pub buckets: Option<u32>,
+    pub server_histograms: Option<bool>, // default true
+}
+
+#[derive(Serialize)]
+pub struct ValueFrequency {
+    pub value: serde_json::Value,
+    pub count: u64,
+}
+
+#[derive(Serialize)]
+pub struct HistogramBucket {
+    pub lower: serde_json::Value,
+    pub upper: serde_json::Value,
+    pub frequency: f64,         // fraction of all rows
+    pub count: Option<u64>,     // computed histograms only
+    pub distinct: Option<u64>,  // server equi-height histograms only
+}
+
+#[derive(Serialize)]
+pub struct ColumnProfile {
+    pub name: String,
+    pub data_type: String,
+    pub null_count: u64,
+    pub null_ratio: f64,
+    pub distinct_count: u64,
+    pub distinct_exact: bool,
+    pub min: serde_json::Value,
+    pub max: serde_json::Value,
+    pub avg_length: Option<f64>,
+    pub top_values: Vec<ValueFrequency>,
+    pub histogram: Vec<HistogramBucket>,
+    pub histogram_source: Option<String>, // "server" or "computed"
+}
+
+#[derive(Serialize)]
+pub struct TableProfile {
+    pub rows: u64,
+    pub sampled: bool,
+    pub columns: Vec<ColumnProfile>,
+}
+
+const EXACT_DISTINCT_LIMIT: u64 = 1_000_000;
+
+#[derive(PartialEq, Clone, Copy)]
+enum Kind {
+    Text,     // CHAR_LENGTH, comparable
+    Binary,   // LENGTH, comparable
+    Lob,      // LENGTH only (BLOB, JSON)
+    Numeric,  // comparable, histogram on the value
+    Temporal, // comparable, histogram on TO_SECONDS
+    Other,    // comparable, no length or histogram (TIME, BIT)
+    Spatial,  // null and distinct counts only
+}
+
+fn kind_of(col: &ColumnMeta) -> Kind {
+    match col.data_type.as_str() {
+        "char" | "varchar" | "tinytext" | "text" | "mediumtext" | "longtext" | "enum" | "set" => Kind::Text,
+        "binary" | "varbinary" => Kind::Binary,
+        "tinyblob" | "blob" | "mediumblob" | "longblob" | "json" => Kind::Lob,
+        "tinyint" | "smallint" | "mediumint" | "int" | "integer" | "bigint" | "decimal" | "numeric" | "float" | "double" | "real" | "year" => Kind::Numeric,
+        "date" | "datetime" | "timestamp" => Kind::Temporal,
+        "geometry" | "point" | "linestring" | "polygon" | "multipoint" | "multilinestring" | "multipolygon" | "geometrycollection" | "geomcollection" => Kind::Spatial,
+        _ => Kind::Other,
+    }
+}
+
+/// HyperLogLog distinct-count estimator over 64-bit hashes (2^14 registers, ~0.8% error).
+struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    const P: u32 = 14;
+
+    fn new() -> Self {
+        HyperLogLog { registers: vec![0; 1 << Self::P] }
+    }
+
+    fn add(&mut self, hash: u64) {
+        let index = (hash >> (64 - Self::P)) as usize;
+        let rank = ((hash << Self::P) | (1 << (Self::P - 1))).leading_zeros() as u8 + 1;
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+
+    fn estimate(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let sum: f64 = self.registers.iter().map(|&r| 2f64.powi(-(r as i32))).sum();
+        let raw = 0.7213 / (1.0 + 1.079 / m) * m * m / sum;
+        let zeros = self.registers.iter().filter(|&&r| r == 0).count();
+        // Linear counting is more accurate for small cardinalities.
+        let estimate = if raw <= 2.5 * m && zeros > 0 { m * (m / zeros as f64).ln() } else { raw };
+        estimate.round() as u64
+    }
+}
+
+fn as_u64(val: &mysql_async::Value) -> u64 {
+    match val {
+        mysql_async::Value::Int(n) => (*n).max(0) as u64,
+        mysql_async::Value::UInt(n) => *n,
+        mysql_async::Value::Bytes(b) => std::str::from_utf8(b).ok().and_then(|s| s.parse().ok()).unwrap_or(0),
+        _ => 0,
+    }
+}
+
+fn as_f64(val: &mysql_async::Value) -> Option<f64> {
+    match val {
+        mysql_async::Value::Int(n) => Some(*n as f64),
+        mysql_async::Value::UInt(n) => Some(*n as f64),
+        mysql_async::Value::Float(n) => Some(*n as f64),
+        mysql_async::Value::Double(n) => Some(*n),
+        mysql_async::Value::Bytes(b) => std::str::from_utf8(b).ok().and_then(|s| s.parse().ok()),
+        _ => None,
+    }
+}
+
+/// Histogram values from COLUMN_STATISTICS; strings are stored as `base64:typeNNN:...`.
+fn histogram_value(val: &serde_json::Value) -> serde_json::Value {
+    let Some(s) = val.as_str() else { return val.clone() };
+    let Some(encoded) = s.strip_prefix("base64:").and_then(|rest| rest.split_once(':')).map(|(_, data)| data) else {
+        return val.clone();
+    };
+    match base64::engine::general_purpose::STANDARD.decode(encoded) {
+        Ok(bytes) => mysql_to_json(mysql_async::Value::Bytes(bytes)),
+        Err(_) => val.clone(),
+    }
+}
+
+/// Converts a server histogram (cumulative frequencies) into per-bucket frequencies.
+fn server_histogram(histogram: &serde_json::Value) -> Vec<HistogramBucket> {
+    let singleton = histogram.get("histogram-type").and_then(|t| t.as_str()) == Some("singleton");
+    let Some(buckets) = histogram.get("buckets").and_then(|b| b.as_array()) else { return Vec::new() };
+    let mut previous = 0.0;
+    buckets
+        .iter()
+        .filter_map(|bucket| {
+            let b = bucket.as_array()?;
+            let (lower, upper, cumulative, distinct) = if singleton {
+                (histogram_value(b.first()?), histogram_value(b.first()?), b.get(1)?.as_f64()?, None)
+            } else {
+                (histogram_value(b.first()?), histogram_value(b.get(1)?), b.get(2)?.as_f64()?, b.get(3).and_then(|d| d.as_u64()))
+            };
+            let frequency = cumulative - previous;
+            previous = cumulative;
+            Some(HistogramBucket { lower, upper, frequency, count: None, distinct })
+        })
+        .collect()
+}
+
+/// Profiles the columns of a table: null ratio, distinct count, min/max, average length,
+/// most frequent values and a histogram for numeric and date columns.
+///
+/// With `sample_rows` the statistics cover the first N rows in storage order, which is
+/// fast but not a random sample. Distinct counts above `EXACT_DISTINCT_LIMIT` rows are
+/// HyperLogLog estimates unless `exact_distinct` is set. Server histograms (MySQL 8
+/// `ANALYZE TABLE ... UPDATE HISTOGRAM`) are preferred and describe the whole table.
+#[tauri::command]
+pub async fn profile_table(db: String, table: String, options: Option<ProfileOptions>, state: State<'_, AppState>) -> Result<TableProfile, String> {
+    let opts = options.unwrap_or_default();
+    let top_n = opts.top_n.unwrap_or(10).clamp(1, 1000);
+    let buckets = opts.buckets.unwrap_or(10).clamp(1, 1000);
+
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let mut columns = load_column_meta(&mut conn, &db, &table).await?;
+    if let Some(wanted) = opts.columns.as_ref().filter(|c| !c.is_empty()) {
+        if let Some(missing) = wanted.iter().find(|w| !columns.iter().any(|c| &c.name == *w)) {
+            return Err(format!("Column `{}` not found", missing));
+        }
+        columns.retain(|c| wanted.contains(&c.name));
+    }
+    let kinds: Vec<Kind> = columns.iter().map(kind_of).collect();
+    let names: Vec<String> = columns.iter().map(|c| quote_identifier(&c.name)).collect();
+
+    let table_ref = format!("{}.{}", quote_identifier(&db), quote_identifier(&table));
+    let source = match opts.sample_rows {
+        Some(n) => format!("(SELECT {} FROM {} LIMIT {}) AS s", names.join(", "), table_ref, n),
+        None => table_ref,
+    };
+
+    // One scan for counts, extremes and lengths. These queries have no parameters and go
+    // over the text protocol, so min/max and top values read the way the grid shows them.
+    let mut exprs = vec!["COUNT(*)".to_string()];
+    for (name, kind) in names.iter().zip(&kinds) {
+        exprs.push(format!("COUNT({})", name));
+        if matches!(kind, Kind::Lob | Kind::Spatial) {
+            exprs.push("NULL, NULL".to_string());
+        } else {
+            exprs.push(format!("MIN({0}), MAX({0})", name));
+        }
+        exprs.push(match kind {
+            Kind::Text => format!("AVG(CHAR_LENGTH({}))", name),
+            Kind::Binary | Kind::Lob => format!("AVG(LENGTH({}))", name),
+            _ => "NULL".to_string(),
+        });
+    }
+    let row: Option<mysql_async::Row> = conn
+        .query_first(format!("SELECT {} FROM {}", exprs.join(", "), source))
+        .await
+        .map_err(|e| e.to_string())?;
+    let values = row.map(|r| r.unwrap()).unwrap_or_default();
+    let total = values.first().map(as_u64).unwrap_or(0);
+
+    let mut profiles: Vec<ColumnProfile> = columns
+        .iter()
+        .enumerate()
+        .map(|(i, col)| {
+            let base = 1 + i * 4;
+            let non_null = values.get(base).map(as_u64).unwrap_or(0);
+            let null_count = total.saturating_sub(non_null);
+            ColumnProfile {
+                name: col.name.clone(),
+                data_type: col.column_type.clone(),
+                null_count,
+                null_ratio: if total > 0 { null_count as f64 / total as f64 } else { 0.0 },
+                distinct_count: 0,
+                distinct_exact: true,
+                min: values.get(base + 1).cloned().map(mysql_to_json).unwrap_or_default(),
+                max: values.get(base + 2).cloned().map(mysql_to_json).unwrap_or_default(),
+                avg_length: values.get(base + 3).and_then(as_f64),
+                top_values: Vec::new(),
+                histogram: Vec::new(),
+                histogram_source: None,
+            }
+        })
+        .collect();
+    if columns.is_empty() || total == 0 {
+        return Ok(TableProfile { rows: total, sampled: opts.sample_rows.is_some(), columns: profiles });
+    }
+
+    // Distinct counts
+    let exact = opts.exact_distinct.unwrap_or(total <= EXACT_DISTINCT_LIMIT);
+    if exact {
+        let exprs: Vec<String> = names.iter().map(|n| format!("COUNT(DISTINCT {})", n)).collect();
+        let row: Option<mysql_async::Row> = conn
+            .query_first(format!("SELECT {} FROM {}", exprs.join(", "), source))
+            .await
+            .map_err(|e| e.to_string())?;
+        let counts = row.map(|r| r.unwrap()).unwrap_or_default();
+        for (profile, count) in profiles.iter_mut().zip(&counts) {
+            profile.distinct_count = as_u64(count);
+        }
+    } else {
+        // Hashing happens on the server so only 64-bit integers cross the wire.
+        let exprs: Vec<String> = names.iter().map(|n| format!("CONV(LEFT(MD5({}), 16), 16, 10)", n)).collect();
+        let mut sketches: Vec<HyperLogLog> = names.iter().map(|_| HyperLogLog::new()).collect();
+        let mut result = conn
+            .query_iter(format!("SELECT {} FROM {}", exprs.join(", "), source))
+            .await
+            .map_err(|e| e.to_string())?;
+        result
+            .for_each(|row| {
+                for (i, sketch) in sketches.iter_mut().enumerate() {
+                    if let Some(mysql_async::Value::Bytes(b)) = row.as_ref(i) {
+                        if let Some(hash) = std::str::from_utf8(b).ok().and_then(|s| s.parse::<u64>().ok()) {
+                            sketch.add(hash);
+                        }
+                    }
+                }
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+        for (profile, sketch) in profiles.iter_mut().zip(&sketches) {
+            profile.distinct_count = sketch.estimate().min(total - profile.null_count);
+            profile.distinct_exact = false;
+        }
+    }
+
+    // Most frequent values
+    for (i, profile) in profiles.iter_mut().enumerate() {
+        if kinds[i] == Kind::Spatial || profile.null_count == total {
+            continue;
+        }
+        let rows: Vec<(mysql_async::Value, u64)> = conn
+            .query(format!(
+                "SELECT {0}, COUNT(*) AS c FROM {1} WHERE {0} IS NOT NULL GROUP BY {0} ORDER BY c DESC LIMIT {2}",
+                names[i], source, top_n
+            ))
+            .await
+            .map_err(|e| e.to_string())?;
+        profile.top_values = rows.into_iter().map(|(value, count)| ValueFrequency { value: mysql_to_json(value), count }).collect();
+    }
+
+    // Histograms: server statistics first, then equi-width buckets computed here.
+    if opts.server_histograms.unwrap_or(true) {
+        let stats: Result<Vec<(String, String)>, _> = conn
+            .exec(
+                "SELECT COLUMN_NAME, CAST(HISTOGRAM AS CHAR) FROM information_schema.COLUMN_STATISTICS WHERE SCHEMA_NAME = ? AND TABLE_NAME = ?",
+                (&db, &table),
+            )
+            .await;
+        // Only MySQL 8 has COLUMN_STATISTICS.
+        for (column, histogram) in stats.unwrap_or_default() {
+            let Some(profile) = profiles.iter_mut().find(|p| p.name == column) else { continue };
+            let Ok(histogram) = serde_json::from_str::<serde_json::Value>(&histogram) else { continue };
+            profile.histogram = server_histogram(&histogram);
+            profile.histogram_source = Some("server".to_string());
+        }
+    }
+    for (i, profile) in profiles.iter_mut().enumerate() {
+        if profile.histogram_source.is_some() || profile.null_count == total {
+            continue;
+        }
+        let position = match kinds[i] {
+            Kind::Numeric => format!("{} + 0.0", names[i]),
+            Kind::Temporal => format!("TO_SECONDS({})", names[i]),
+            _ => continue,
+        };
+        let rows: Vec<(mysql_async::Value, u64, mysql_async::Value, mysql_async::Value)> = conn
+            .query(format!(
+                "SELECT IF(r.hi = r.lo, 0, LEAST(FLOOR((h.x - r.lo) * {n} / (r.hi - r.lo)), {last})) AS b, COUNT(*), MIN(h.v), MAX(h.v)
+                 FROM (SELECT {col} AS v, {pos} AS x FROM {src} WHERE {col} IS NOT NULL) AS h
+                 CROSS JOIN (SELECT MIN({pos}) AS lo, MAX({pos}) AS hi FROM {src}) AS r
+                 GROUP BY b ORDER BY b",
+                n = buckets, last = buckets - 1, col = names[i], pos = position, src = source
+            ))
+            .await
+            .map_err(|e| e.to_string())?;
+        profile.histogram = rows
+            .into_iter()
+            .map(|(_, count, lower, upper)| HistogramBucket {
+                lower: mysql_to_json(lower),
+                upper: mysql_to_json(upper),
+                frequency: count as f64 / total as f64,
+                count: Some(count),
+                distinct: None,
+            })
+            .collect();
+        profile.histogram_source = Some("computed".to_string());
+    }
+
+    Ok(TableProfile { rows: total, sampled: opts.sample_rows.is_some(), columns: profiles })
+}
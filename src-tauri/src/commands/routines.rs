@@ -11,6 +11,7 @@ pub struct Routine {
     pub data_type: String,    // Return type for functions
     pub created: String,
     pub last_altered: String,
+    pub comment: String,
 }
 
 #[tauri::command]
@@ -22,23 +23,24 @@ pub async fn get_routines(db: String, state: State<'_, AppState>) -> Result<Vec<
     let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
 
     let sql = format!(
-        "SELECT ROUTINE_NAME, ROUTINE_TYPE, DATA_TYPE, CREATED, LAST_ALTERED 
+        "SELECT ROUTINE_NAME, ROUTINE_TYPE, DATA_TYPE, CREATED, LAST_ALTERED, ROUTINE_COMMENT 
          FROM information_schema.ROUTINES 
          WHERE ROUTINE_SCHEMA = '{}'
          ORDER BY ROUTINE_NAME ASC",
         db
     );
 
-    let rows: Vec<(String, String, Option<String>, NaiveDateTime, NaiveDateTime)> = 
+    let rows: Vec<(String, String, Option<String>, NaiveDateTime, NaiveDateTime, String)> = 
         conn.query(sql).await.map_err(|e| e.to_string())?;
 
-    let routines = rows.into_iter().map(|(name, r_type, d_type, created, altered)| {
+    let routines = rows.into_iter().map(|(name, r_type, d_type, created, altered, comment)| {
         Routine {
             name,
             routine_type: r_type,
             data_type: d_type.unwrap_or_default(),
             created: created.format("%Y-%m-%d %H:%M:%S").to_string(),
             last_altered: altered.format("%Y-%m-%d %H:%M:%S").to_string(),
+            comment,
         }
     }).collect();
 
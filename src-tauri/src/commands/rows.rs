@@ -0,0 +1,551 @@
+use tauri::State;
+use crate::state::AppState;
+use crate::commands::common::{mysql_to_json, quote_identifier};
+use crate::commands::values::{convert_row, format_cell_errors, json_param, load_column_meta, CellError, ColumnMeta};
+use mysql_async::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// How rows of a table are identified for editing.
+#[derive(Serialize, Clone, Debug)]
+pub struct RowKey {
+    pub kind: String, // "primary", "unique" or "none" (match on every column)
+    pub index_name: Option<String>,
+    pub columns: Vec<String>,
+    #[serde(skip)]
+    pub data_types: Vec<String>, // lowercase DATA_TYPE of each key column
+}
+
+/// Picks the row identity for a table: every primary key column, else the first unique
+/// index whose columns are all NOT NULL, else all columns of the table. Indexes with
+/// functional key parts cannot identify rows by column values and are skipped.
+pub async fn load_row_key(conn: &mut mysql_async::Conn, db: &str, table: &str) -> Result<RowKey, String> {
+    #[allow(clippy::type_complexity)]
+    let rows: Vec<(String, Option<String>, Option<String>, Option<String>)> = conn
+        .exec(
+            "SELECT s.INDEX_NAME, s.COLUMN_NAME, c.IS_NULLABLE, c.DATA_TYPE
+             FROM information_schema.STATISTICS s
+             LEFT JOIN information_schema.COLUMNS c
+               ON c.TABLE_SCHEMA = s.TABLE_SCHEMA AND c.TABLE_NAME = s.TABLE_NAME AND c.COLUMN_NAME = s.COLUMN_NAME
+             WHERE s.TABLE_SCHEMA = ? AND s.TABLE_NAME = ? AND s.NON_UNIQUE = 0
+             ORDER BY s.INDEX_NAME = 'PRIMARY' DESC, s.INDEX_NAME, s.SEQ_IN_INDEX",
+            (db, table),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // (index, columns, unusable): unusable when a part is nullable or an expression.
+    let mut indexes: Vec<(String, Vec<String>, bool)> = Vec::new();
+    let mut types = HashMap::new();
+    for (index, column, nullable, data_type) in rows {
+        let unusable = column.is_none() || nullable.as_deref() != Some("NO");
+        if let Some(column) = &column {
+            types.insert(column.clone(), data_type.unwrap_or_default().to_lowercase());
+        }
+        match indexes.iter_mut().find(|(name, _, _)| *name == index) {
+            Some((_, cols, bad)) => {
+                cols.extend(column);
+                *bad |= unusable;
+            }
+            None => indexes.push((index, column.into_iter().collect(), unusable)),
+        }
+    }
+
+    if let Some((name, columns, _)) = indexes.into_iter().find(|(_, _, unusable)| !unusable) {
+        let data_types = columns.iter().map(|c| types[c].clone()).collect();
+        return Ok(RowKey {
+            kind: if name == "PRIMARY" { "primary".to_string() } else { "unique".to_string() },
+            index_name: Some(name),
+            columns,
+            data_types,
+        });
+    }
+
+    let columns: Vec<(String, String)> = conn
+        .exec(
+            "SELECT COLUMN_NAME, DATA_TYPE FROM information_schema.COLUMNS WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ? ORDER BY ORDINAL_POSITION",
+            (db, table),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    let (columns, data_types) = columns.into_iter().map(|(name, data_type)| (name, data_type.to_lowercase())).unzip();
+
+    Ok(RowKey { kind: "none".to_string(), index_name: None, columns, data_types })
+}
+
+/// A WHERE clause (without the keyword) with its bound parameters.
+pub struct Condition {
+    pub sql: String,
+    pub params: Vec<mysql_async::Value>,
+}
+
+/// Compares a column with a value the client saw. Uses `<=>` so NULLs match. FLOAT and
+/// DOUBLE values are shown rounded, so those match within a relative tolerance instead.
+/// A bound string is a JSON string scalar to a JSON column, so JSON values are cast first.
+fn value_match(column: &str, data_type: &str, val: &serde_json::Value) -> (String, Vec<mysql_async::Value>) {
+    let col = quote_identifier(column);
+    if val.is_null() {
+        return (format!("{} <=> ?", col), vec![json_param(val)]);
+    }
+    match data_type {
+        "float" => (format!("ABS({} - ?) <= ABS(?) * 1e-6", col), vec![json_param(val), json_param(val)]),
+        "double" | "real" => (format!("ABS({} - ?) <= ABS(?) * 1e-12", col), vec![json_param(val), json_param(val)]),
+        "json" => (format!("{} <=> CAST(? AS JSON)", col), vec![json_param(val)]),
+        _ => (format!("{} <=> ?", col), vec![json_param(val)]),
+    }
+}
+
+/// Matches the row described by `row`, which maps column names to the values the client
+/// last saw.
+pub fn identity_condition(key: &RowKey, row: &HashMap<String, serde_json::Value>) -> Result<Condition, String> {
+    let mut parts = Vec::new();
+    let mut params = Vec::new();
+    for (i, col) in key.columns.iter().enumerate() {
+        let val = row
+            .get(col)
+            .ok_or(format!("Row identity is missing a value for `{}`", col))?;
+        let (sql, values) = value_match(col, key.data_types.get(i).map_or("", |t| t.as_str()), val);
+        parts.push(sql);
+        params.extend(values);
+    }
+    if parts.is_empty() {
+        return Err("Table has no columns to identify rows by".to_string());
+    }
+    Ok(Condition { sql: parts.join(" AND "), params })
+}
+
+/// For keyless tables, counts the rows an identity condition matches so callers can warn
+/// that only one of several identical rows will be touched.
+pub async fn duplicate_warning(
+    conn: &mut mysql_async::Conn,
+    db: &str,
+    table: &str,
+    key: &RowKey,
+    condition: &Condition,
+) -> Result<Option<String>, String> {
+    if key.kind != "none" {
+        return Ok(None);
+    }
+    let count: Option<u64> = conn
+        .exec_first(format!("SELECT COUNT(*) FROM `{}`.`{}` WHERE {}", db, table, condition.sql), condition.params.clone())
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(match count.unwrap_or(0) {
+        n if n > 1 => Some(format!(
+            "Table has no primary or unique key and {} identical rows match; only one is affected",
+            n
+        )),
+        _ => None,
+    })
+}
+
+#[derive(Serialize)]
+pub struct InsertRowsResult {
+    pub inserted: u64,
+    pub keys: Vec<HashMap<String, serde_json::Value>>, // key of each new row, in input order
+}
+
+#[derive(Serialize)]
+pub struct DeleteRowsResult {
+    pub deleted: u64,
+    pub not_found: usize,
+    pub warnings: Vec<String>,
+}
+
+/// Key values of a freshly inserted row: provided values, or the generated auto-increment id.
+fn new_row_key(
+    key: &RowKey,
+    columns: &[ColumnMeta],
+    provided: &HashMap<String, serde_json::Value>,
+    insert_id: Option<u64>,
+) -> HashMap<String, serde_json::Value> {
+    let mut out = HashMap::new();
+    if key.kind == "none" {
+        return out;
+    }
+    for col in &key.columns {
+        let auto = columns.iter().any(|c| &c.name == col && c.is_auto_increment());
+        // NULL or 0 in an auto-increment column asks the server for the next id.
+        let is_placeholder = |v: &serde_json::Value| v.is_null() || (auto && v.as_u64() == Some(0));
+        let given = provided.get(col).filter(|v| !is_placeholder(v));
+        let value = match (given, auto, insert_id) {
+            (Some(v), _, _) => v.clone(),
+            (None, true, Some(id)) => serde_json::Value::from(id),
+            _ => serde_json::Value::Null,
+        };
+        out.insert(col.clone(), value);
+    }
+    out
+}
+
+/// INSERT for already converted values; an empty list inserts an all-default row.
+fn insert_statement(db: &str, table: &str, values: &[(String, mysql_async::Value)]) -> (String, Vec<mysql_async::Value>) {
+    let col_list: Vec<String> = values.iter().map(|(name, _)| quote_identifier(name)).collect();
+    let placeholders = vec!["?"; values.len()].join(", ");
+    (
+        format!("INSERT INTO `{}`.`{}` ({}) VALUES ({})", db, table, col_list.join(", "), placeholders),
+        values.iter().map(|(_, v)| v.clone()).collect(),
+    )
+}
+
+/// Inserts rows in one transaction. Columns left out of a row take their DEFAULT
+/// (or the next auto-increment value), so an empty object inserts an all-default row.
+#[tauri::command]
+pub async fn insert_rows(db: String, table: String, rows: Vec<HashMap<String, serde_json::Value>>, state: State<'_, AppState>) -> Result<InsertRowsResult, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let columns = load_column_meta(&mut conn, &db, &table).await?;
+    let key = load_row_key(&mut conn, &db, &table).await?;
+
+    // Validate everything before touching the table.
+    let mut errors = Vec::new();
+    let converted: Vec<Vec<(String, mysql_async::Value)>> = rows
+        .iter()
+        .enumerate()
+        .map(|(i, row)| convert_row(&columns, row, "insert", i, &mut errors))
+        .collect();
+    if !errors.is_empty() {
+        return Err(format_cell_errors(&errors));
+    }
+
+    let mut tx = conn.start_transaction(mysql_async::TxOpts::default()).await.map_err(|e| e.to_string())?;
+    let mut keys = Vec::new();
+    for (i, values) in converted.iter().enumerate() {
+        let (sql, params) = insert_statement(&db, &table, values);
+        tx.exec_drop(sql, params).await.map_err(|e| format!("Row {}: {}", i + 1, e))?;
+        keys.push(new_row_key(&key, &columns, &rows[i], tx.last_insert_id()));
+    }
+    tx.commit().await.map_err(|e| e.to_string())?;
+
+    Ok(InsertRowsResult { inserted: keys.len() as u64, keys })
+}
+
+/// Copies existing rows (identified as in `update_cell`) into new rows. Auto-increment
+/// and generated columns are left for the server to fill in. Columns of a primary or unique
+/// key cannot be copied, so `overrides` must give each row new values for them; it can also
+/// replace any other column.
+#[tauri::command]
+pub async fn duplicate_rows(
+    db: String,
+    table: String,
+    rows: Vec<HashMap<String, serde_json::Value>>,
+    overrides: Option<Vec<HashMap<String, serde_json::Value>>>,
+    state: State<'_, AppState>,
+) -> Result<InsertRowsResult, String> {
+    let overrides = overrides.unwrap_or_default();
+    if overrides.len() > rows.len() {
+        return Err(format!("Got {} overrides for {} rows", overrides.len(), rows.len()));
+    }
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let columns = load_column_meta(&mut conn, &db, &table).await?;
+    let key = load_row_key(&mut conn, &db, &table).await?;
+    let unique: Vec<String> = conn
+        .exec(
+            "SELECT DISTINCT COLUMN_NAME FROM information_schema.STATISTICS
+             WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ? AND NON_UNIQUE = 0 AND COLUMN_NAME IS NOT NULL",
+            (&db, &table),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    let copyable: Vec<&ColumnMeta> = columns.iter().filter(|c| !c.is_auto_increment() && !c.is_generated()).collect();
+
+    // Validate every row before touching the table.
+    let no_overrides = HashMap::new();
+    let mut errors = Vec::new();
+    let mut replaced = Vec::new();
+    for i in 0..rows.len() {
+        let row_overrides = overrides.get(i).unwrap_or(&no_overrides);
+        for col in copyable.iter().filter(|c| unique.contains(&c.name)) {
+            if !row_overrides.contains_key(&col.name) {
+                errors.push(CellError {
+                    operation: "duplicate".to_string(),
+                    row: i,
+                    column: col.name.clone(),
+                    message: "is part of a unique key and needs a new value".to_string(),
+                });
+            }
+        }
+        replaced.push(convert_row(&columns, row_overrides, "duplicate", i, &mut errors));
+    }
+    if !errors.is_empty() {
+        return Err(format_cell_errors(&errors));
+    }
+
+    let mut tx = conn.start_transaction(mysql_async::TxOpts::default()).await.map_err(|e| e.to_string())?;
+    let mut keys = Vec::new();
+    for (i, row) in rows.iter().enumerate() {
+        let values = &replaced[i];
+        let mut targets: Vec<String> = values.iter().map(|(name, _)| quote_identifier(name)).collect();
+        let mut sources = vec!["?".to_string(); values.len()];
+        for col in &copyable {
+            if !values.iter().any(|(name, _)| name == &col.name) {
+                targets.push(quote_identifier(&col.name));
+                sources.push(quote_identifier(&col.name));
+            }
+        }
+        if targets.is_empty() {
+            return Err("Table has no columns that can be copied".to_string());
+        }
+        let condition = identity_condition(&key, row)?;
+        let sql = format!(
+            "INSERT INTO `{db}`.`{table}` ({targets}) SELECT {sources} FROM `{db}`.`{table}` WHERE {cond} LIMIT 1",
+            db = db, table = table, targets = targets.join(", "), sources = sources.join(", "), cond = condition.sql
+        );
+        let mut params: Vec<mysql_async::Value> = values.iter().map(|(_, v)| v.clone()).collect();
+        params.extend(condition.params);
+        tx.exec_drop(sql, params).await.map_err(|e| format!("Row {}: {}", i + 1, e))?;
+        if tx.affected_rows() == 0 {
+            return Err(format!("Row {}: source row not found", i + 1));
+        }
+        // Key columns are either auto-increment or were given in the overrides.
+        keys.push(new_row_key(&key, &columns, overrides.get(i).unwrap_or(&no_overrides), tx.last_insert_id()));
+    }
+    tx.commit().await.map_err(|e| e.to_string())?;
+
+    Ok(InsertRowsResult { inserted: keys.len() as u64, keys })
+}
+
+/// Deletes rows by identity in one transaction. Rows that no longer exist are counted in
+/// `not_found` rather than failing the batch.
+#[tauri::command]
+pub async fn delete_rows(db: String, table: String, rows: Vec<HashMap<String, serde_json::Value>>, state: State<'_, AppState>) -> Result<DeleteRowsResult, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let key = load_row_key(&mut conn, &db, &table).await?;
+    let mut warnings = Vec::new();
+    let mut conditions = Vec::new();
+    for row in &rows {
+        let condition = identity_condition(&key, row)?;
+        if let Some(w) = duplicate_warning(&mut conn, &db, &table, &key, &condition).await? {
+            if !warnings.contains(&w) {
+                warnings.push(w);
+            }
+        }
+        conditions.push(condition);
+    }
+
+    let limit = if key.kind == "none" { " LIMIT 1" } else { "" };
+    let mut tx = conn.start_transaction(mysql_async::TxOpts::default()).await.map_err(|e| e.to_string())?;
+    let mut deleted = 0;
+    let mut not_found = 0;
+    for condition in conditions {
+        tx.exec_drop(format!("DELETE FROM `{}`.`{}` WHERE {}{}", db, table, condition.sql, limit), condition.params)
+            .await
+            .map_err(|e| e.to_string())?;
+        match tx.affected_rows() {
+            0 => not_found += 1,
+            n => deleted += n,
+        }
+    }
+    tx.commit().await.map_err(|e| e.to_string())?;
+
+    Ok(DeleteRowsResult { deleted, not_found, warnings })
+}
+
+// --- Change sets ---
+
+#[derive(Deserialize)]
+pub struct RowUpdate {
+    pub original: HashMap<String, serde_json::Value>, // values the client last saw
+    pub values: HashMap<String, serde_json::Value>,   // column -> new value
+}
+
+#[derive(Deserialize, Default)]
+pub struct ChangeSet {
+    #[serde(default)]
+    pub inserts: Vec<HashMap<String, serde_json::Value>>,
+    #[serde(default)]
+    pub updates: Vec<RowUpdate>,
+    #[serde(default)]
+    pub deletes: Vec<HashMap<String, serde_json::Value>>, // original row values
+}
+
+#[derive(Serialize)]
+pub struct ChangeConflict {
+    pub operation: String, // "update" or "delete"
+    pub index: usize,      // position within `updates` / `deletes`
+    pub reason: String,    // "modified" or "deleted"
+    pub current: Option<HashMap<String, serde_json::Value>>,
+}
+
+#[derive(Serialize, Default)]
+pub struct ChangeSetResult {
+    pub committed: bool,
+    pub inserted_keys: Vec<HashMap<String, serde_json::Value>>,
+    pub updated: u64,
+    pub deleted: u64,
+    pub conflicts: Vec<ChangeConflict>,
+    pub errors: Vec<CellError>,
+    pub warnings: Vec<String>,
+}
+
+/// Identity plus every other original value, so the statement only matches a row
+/// nobody else has changed since the client read it.
+fn optimistic_condition(key: &RowKey, columns: &[ColumnMeta], original: &HashMap<String, serde_json::Value>) -> Result<Condition, String> {
+    if let Some(unknown) = original.keys().find(|k| !columns.iter().any(|c| &c.name == *k)) {
+        return Err(format!("Unknown column `{}`", unknown));
+    }
+    let mut condition = identity_condition(key, original)?;
+    for col in columns {
+        if key.columns.contains(&col.name) {
+            continue;
+        }
+        if let Some(val) = original.get(&col.name) {
+            let (sql, params) = value_match(&col.name, &col.data_type, val);
+            condition.sql.push_str(&format!(" AND {}", sql));
+            condition.params.extend(params);
+        }
+    }
+    Ok(condition)
+}
+
+async fn current_row(
+    tx: &mut mysql_async::Transaction<'_>,
+    db: &str,
+    table: &str,
+    key: &RowKey,
+    original: &HashMap<String, serde_json::Value>,
+) -> Result<Option<HashMap<String, serde_json::Value>>, String> {
+    let condition = identity_condition(key, original)?;
+    let mut result = tx
+        .exec_iter(format!("SELECT * FROM `{}`.`{}` WHERE {} LIMIT 1", db, table, condition.sql), condition.params)
+        .await
+        .map_err(|e| e.to_string())?;
+    let columns: Vec<String> = result
+        .columns()
+        .map(|cols| cols.iter().map(|c| c.name_str().into_owned()).collect())
+        .unwrap_or_default();
+    let rows: Vec<mysql_async::Row> = result.collect().await.map_err(|e| e.to_string())?;
+    Ok(rows.into_iter().next().map(|row| {
+        columns
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.clone(), mysql_to_json(row.get(i).unwrap_or(mysql_async::Value::NULL))))
+            .collect()
+    }))
+}
+
+/// Applies staged grid edits in a single transaction: deletes, then updates, then inserts.
+/// Values are validated against the column types first; invalid cells are returned in
+/// `errors` without writing anything. Updates and deletes carry the original values in
+/// their WHERE clause; if any of them no longer matches, everything is rolled back and the
+/// conflicts are returned.
+#[tauri::command]
+pub async fn apply_changes(db: String, table: String, changes: ChangeSet, state: State<'_, AppState>) -> Result<ChangeSetResult, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let columns = load_column_meta(&mut conn, &db, &table).await?;
+    let key = load_row_key(&mut conn, &db, &table).await?;
+    let limit = if key.kind == "none" { " LIMIT 1" } else { "" };
+    let mut result = ChangeSetResult::default();
+    if key.kind == "none" && (!changes.updates.is_empty() || !changes.deletes.is_empty()) {
+        result.warnings.push("Table has no primary or unique key; rows are matched on all original values".to_string());
+    }
+
+    let update_values: Vec<Vec<(String, mysql_async::Value)>> = changes
+        .updates
+        .iter()
+        .enumerate()
+        .map(|(i, u)| convert_row(&columns, &u.values, "update", i, &mut result.errors))
+        .collect();
+    let insert_values: Vec<Vec<(String, mysql_async::Value)>> = changes
+        .inserts
+        .iter()
+        .enumerate()
+        .map(|(i, row)| convert_row(&columns, row, "insert", i, &mut result.errors))
+        .collect();
+    if !result.errors.is_empty() {
+        return Ok(result);
+    }
+
+    let mut tx = conn.start_transaction(mysql_async::TxOpts::default()).await.map_err(|e| e.to_string())?;
+
+    for (i, original) in changes.deletes.iter().enumerate() {
+        let condition = optimistic_condition(&key, &columns, original)?;
+        tx.exec_drop(format!("DELETE FROM `{}`.`{}` WHERE {}{}", db, table, condition.sql, limit), condition.params)
+            .await
+            .map_err(|e| format!("Delete {}: {}", i + 1, e))?;
+        match tx.affected_rows() {
+            0 => {
+                let current = current_row(&mut tx, &db, &table, &key, original).await?;
+                result.conflicts.push(ChangeConflict {
+                    operation: "delete".to_string(),
+                    index: i,
+                    reason: if current.is_some() { "modified" } else { "deleted" }.to_string(),
+                    current,
+                });
+            }
+            n => result.deleted += n,
+        }
+    }
+
+    for (i, update) in changes.updates.iter().enumerate() {
+        let values = &update_values[i];
+        if values.is_empty() {
+            continue;
+        }
+        let condition = optimistic_condition(&key, &columns, &update.original)?;
+        let assignments: Vec<String> = values.iter().map(|(name, _)| format!("{} = ?", quote_identifier(name))).collect();
+        let mut params: Vec<mysql_async::Value> = values.iter().map(|(_, v)| v.clone()).collect();
+        params.extend(condition.params);
+        tx.exec_drop(format!("UPDATE `{}`.`{}` SET {} WHERE {}{}", db, table, assignments.join(", "), condition.sql, limit), params)
+            .await
+            .map_err(|e| format!("Update {}: {}", i + 1, e))?;
+        if tx.affected_rows() > 0 {
+            result.updated += tx.affected_rows();
+            continue;
+        }
+
+        // 0 affected rows also means "already had these values"; only a missing match is a conflict.
+        let mut expected = update.original.clone();
+        expected.extend(update.values.clone());
+        let expected_condition = optimistic_condition(&key, &columns, &expected)?;
+        let unchanged: Option<u8> = tx
+            .exec_first(format!("SELECT 1 FROM `{}`.`{}` WHERE {} LIMIT 1", db, table, expected_condition.sql), expected_condition.params)
+            .await
+            .map_err(|e| e.to_string())?;
+        if unchanged.is_none() {
+            let current = current_row(&mut tx, &db, &table, &key, &update.original).await?;
+            result.conflicts.push(ChangeConflict {
+                operation: "update".to_string(),
+                index: i,
+                reason: if current.is_some() { "modified" } else { "deleted" }.to_string(),
+                current,
+            });
+        }
+    }
+
+    if !result.conflicts.is_empty() {
+        tx.rollback().await.map_err(|e| e.to_string())?;
+        result.updated = 0;
+        result.deleted = 0;
+        return Ok(result);
+    }
+
+    for (i, values) in insert_values.iter().enumerate() {
+        let (sql, params) = insert_statement(&db, &table, values);
+        tx.exec_drop(sql, params).await.map_err(|e| format!("Insert {}: {}", i + 1, e))?;
+        result.inserted_keys.push(new_row_key(&key, &columns, &changes.inserts[i], tx.last_insert_id()));
+    }
+
+    tx.commit().await.map_err(|e| e.to_string())?;
+    result.committed = true;
+    Ok(result)
+}
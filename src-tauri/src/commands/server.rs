@@ -273,6 +273,7 @@ pub async fn connect_db(config: crate::state::DbConfig, state: State<'_, AppStat
 
     let mut guard = state.pool.lock().unwrap();
     *guard = Some(pool);
+    *state.server_id.lock().unwrap() = config.id.clone();
 
     Ok("Connected successfully".into())
 }
@@ -1,4 +1,10 @@
+use tauri::State;
+use crate::state::AppState;
+use crate::commands::common::{mysql_to_json, text_protocol_value};
+use crate::commands::query::QueryResult;
+use mysql_async::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
@@ -9,6 +15,18 @@ pub struct Snippet {
     pub sql: String,
     pub description: Option<String>,
     pub created_at: String,
+    #[serde(default)]
+    pub folder: Option<String>, // "/"-separated path, e.g. "ops/billing"
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub server_id: Option<String>,
+    #[serde(default)]
+    pub database: Option<String>,
+    #[serde(default)]
+    pub favorite: bool,
+    #[serde(default)]
+    pub updated_at: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -32,64 +50,836 @@ fn ensure_app_data_dir() {
     }
 }
 
-#[tauri::command]
-pub async fn get_snippets() -> Result<Vec<Snippet>, String> {
+fn load_snippets() -> Result<Vec<Snippet>, String> {
     ensure_app_data_dir();
     let path = get_snippets_path();
-    
+
     if !path.exists() {
         return Ok(Vec::new());
     }
 
     let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
     let config: SnippetsConfig = serde_json::from_str(&content).unwrap_or(SnippetsConfig { snippets: vec![] });
-    
     Ok(config.snippets)
 }
 
-#[tauri::command]
-pub async fn save_snippet(snippet: Snippet) -> Result<Vec<Snippet>, String> {
+fn write_snippets(snippets: &[Snippet]) -> Result<(), String> {
     ensure_app_data_dir();
-    let path = get_snippets_path();
-    
-    let mut snippets = if path.exists() {
-        let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
-        let config: SnippetsConfig = serde_json::from_str(&content).unwrap_or(SnippetsConfig { snippets: vec![] });
-        config.snippets
-    } else {
-        Vec::new()
-    };
+    let config = SnippetsConfig { snippets: snippets.to_vec() };
+    let json = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+    fs::write(get_snippets_path(), json).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn get_snippets() -> Result<Vec<Snippet>, String> {
+    load_snippets()
+}
+
+#[tauri::command]
+pub async fn save_snippet(mut snippet: Snippet) -> Result<Vec<Snippet>, String> {
+    let mut snippets = load_snippets()?;
+
+    snippet.folder = normalize_folder(snippet.folder.as_deref());
+    snippet.tags = normalize_tags(&snippet.tags);
 
     // Update if exists, else add
     if let Some(pos) = snippets.iter().position(|s| s.id == snippet.id) {
+        snippet.updated_at = Some(chrono::Local::now().to_rfc3339());
         snippets[pos] = snippet;
     } else {
         snippets.push(snippet);
     }
 
-    let config = SnippetsConfig { snippets: snippets.clone() };
-    let json = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
-    fs::write(path, json).map_err(|e| e.to_string())?;
-
+    write_snippets(&snippets)?;
     Ok(snippets)
 }
 
 #[tauri::command]
 pub async fn delete_snippet(id: String) -> Result<Vec<Snippet>, String> {
-    ensure_app_data_dir();
-    let path = get_snippets_path();
-    
-    if !path.exists() {
-        return Ok(Vec::new());
+    let mut snippets = load_snippets()?;
+    snippets.retain(|s| s.id != id);
+    write_snippets(&snippets)?;
+    Ok(snippets)
+}
+
+// --- Library organization ---
+
+fn normalize_folder(folder: Option<&str>) -> Option<String> {
+    let path: Vec<&str> = folder
+        .unwrap_or("")
+        .split(['/', '\\'])
+        .map(|p| p.trim())
+        .filter(|p| !p.is_empty() && *p != "." && *p != "..")
+        .collect();
+    if path.is_empty() { None } else { Some(path.join("/")) }
+}
+
+fn normalize_tags(tags: &[String]) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for t in tags {
+        let t = t.trim().trim_start_matches('#').to_lowercase();
+        if !t.is_empty() && !out.contains(&t) {
+            out.push(t);
+        }
     }
+    out
+}
 
-    let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
-    let mut config: SnippetsConfig = serde_json::from_str(&content).unwrap_or(SnippetsConfig { snippets: vec![] });
-    
-    config.snippets.retain(|s| s.id != id);
-    
-    let json = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
-    fs::write(path, json).map_err(|e| e.to_string())?;
+/// SQL reduced to its significant tokens, used to spot the same query saved twice
+/// with different whitespace, comments or keyword case.
+fn normalized_sql(sql: &str) -> String {
+    use crate::commands::sql_lexer::{tokenize, TokenKind};
+    tokenize(sql)
+        .iter()
+        .filter(|t| t.kind != TokenKind::Whitespace && !t.is_comment())
+        .map(|t| if t.kind == TokenKind::Word { t.upper() } else { t.text.clone() })
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_end_matches(" ;")
+        .to_string()
+}
 
-    Ok(config.snippets)
+#[derive(Deserialize, Default)]
+pub struct SnippetFilter {
+    pub query: Option<String>,
+    pub folder: Option<String>, // includes subfolders
+    pub tags: Option<Vec<String>>,
+    pub server_id: Option<String>,
+    pub database: Option<String>,
+    pub favorites_only: Option<bool>,
+}
+
+/// Filters the library and ranks matches: name hits before description hits before SQL hits.
+/// Snippets without a server/database scope are visible everywhere.
+#[tauri::command]
+pub async fn search_snippets(filter: SnippetFilter) -> Result<Vec<Snippet>, String> {
+    let snippets = load_snippets()?;
+    let terms: Vec<String> = filter.query.as_deref().unwrap_or("").split_whitespace().map(|t| t.to_lowercase()).collect();
+    let folder = normalize_folder(filter.folder.as_deref());
+    let tags = normalize_tags(&filter.tags.unwrap_or_default());
+
+    let mut ranked: Vec<(u32, Snippet)> = snippets
+        .into_iter()
+        .filter(|s| !filter.favorites_only.unwrap_or(false) || s.favorite)
+        .filter(|s| match (&folder, &s.folder) {
+            (None, _) => true,
+            (Some(f), Some(sf)) => sf == f || sf.starts_with(&format!("{}/", f)),
+            (Some(_), None) => false,
+        })
+        .filter(|s| tags.iter().all(|t| s.tags.contains(t)))
+        .filter(|s| match (&filter.server_id, &s.server_id) {
+            (Some(want), Some(have)) => want == have,
+            _ => true,
+        })
+        .filter(|s| match (&filter.database, &s.database) {
+            (Some(want), Some(have)) => want.eq_ignore_ascii_case(have),
+            _ => true,
+        })
+        .filter_map(|s| {
+            let name = s.name.to_lowercase();
+            let desc = s.description.clone().unwrap_or_default().to_lowercase();
+            let sql = s.sql.to_lowercase();
+            let mut score = 0;
+            for t in &terms {
+                if name.contains(t.as_str()) {
+                    score += 100;
+                } else if s.tags.iter().any(|tag| tag.contains(t.as_str())) {
+                    score += 50;
+                } else if desc.contains(t.as_str()) {
+                    score += 20;
+                } else if sql.contains(t.as_str()) {
+                    score += 5;
+                } else {
+                    return None;
+                }
+            }
+            if s.favorite {
+                score += 1;
+            }
+            Some((score, s))
+        })
+        .collect();
+
+    ranked.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.name.to_lowercase().cmp(&b.1.name.to_lowercase())));
+    Ok(ranked.into_iter().map(|(_, s)| s).collect())
+}
+
+#[derive(Serialize)]
+pub struct SnippetLibraryIndex {
+    pub folders: Vec<String>,
+    pub tags: Vec<String>,
+}
+
+#[tauri::command]
+pub async fn get_snippet_library_index() -> Result<SnippetLibraryIndex, String> {
+    let snippets = load_snippets()?;
+    let mut folders: Vec<String> = Vec::new();
+    let mut tags: Vec<String> = Vec::new();
+    for s in &snippets {
+        if let Some(f) = &s.folder {
+            // Register every ancestor so empty intermediate folders still show in the tree.
+            let parts: Vec<&str> = f.split('/').collect();
+            for i in 1..=parts.len() {
+                let p = parts[..i].join("/");
+                if !folders.contains(&p) {
+                    folders.push(p);
+                }
+            }
+        }
+        for t in &s.tags {
+            if !tags.contains(t) {
+                tags.push(t.clone());
+            }
+        }
+    }
+    folders.sort();
+    tags.sort();
+    Ok(SnippetLibraryIndex { folders, tags })
+}
+
+/// Groups of snippets whose SQL is identical once whitespace, comments and keyword case are ignored.
+#[tauri::command]
+pub async fn find_duplicate_snippets() -> Result<Vec<Vec<Snippet>>, String> {
+    let snippets = load_snippets()?;
+    let mut groups: Vec<(String, Vec<Snippet>)> = Vec::new();
+    for s in snippets {
+        let key = normalized_sql(&s.sql);
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, g)) => g.push(s),
+            None => groups.push((key, vec![s])),
+        }
+    }
+    Ok(groups.into_iter().map(|(_, g)| g).filter(|g| g.len() > 1).collect())
+}
+
+// --- Import / export bundles ---
+//
+// A bundle is either a JSON file (`{ "snippets": [...] }`) or a folder of `.sql` files.
+// Each `.sql` file starts with a commented front-matter block so it still runs as-is:
+//
+//   -- ---
+//   -- name: Daily signups
+//   -- tags: reporting, users
+//   -- ---
+//   SELECT ...
+
+fn render_front_matter(s: &Snippet) -> String {
+    let mut out = String::from("-- ---\n");
+    out.push_str(&format!("-- id: {}\n", s.id));
+    out.push_str(&format!("-- name: {}\n", s.name.replace('\n', " ")));
+    if let Some(d) = &s.description {
+        for (i, line) in d.lines().enumerate() {
+            if i == 0 {
+                out.push_str(&format!("-- description: {}\n", line));
+            } else {
+                out.push_str(&format!("--   {}\n", line));
+            }
+        }
+    }
+    if !s.tags.is_empty() {
+        out.push_str(&format!("-- tags: {}\n", s.tags.join(", ")));
+    }
+    if let Some(f) = &s.folder {
+        out.push_str(&format!("-- folder: {}\n", f));
+    }
+    if let Some(v) = &s.server_id {
+        out.push_str(&format!("-- server_id: {}\n", v));
+    }
+    if let Some(v) = &s.database {
+        out.push_str(&format!("-- database: {}\n", v));
+    }
+    if s.favorite {
+        out.push_str("-- favorite: true\n");
+    }
+    out.push_str(&format!("-- created_at: {}\n", s.created_at));
+    out.push_str("-- ---\n");
+    out.push_str(&s.sql);
+    if !s.sql.ends_with('\n') {
+        out.push('\n');
+    }
+    out
+}
+
+fn parse_front_matter(content: &str, fallback_name: &str, fallback_folder: Option<String>) -> Snippet {
+    let mut fields: HashMap<String, String> = HashMap::new();
+    let mut body_start = 0;
+    let mut lines = content.split_inclusive('\n');
+
+    if content.trim_start().starts_with("-- ---") {
+        let mut offset = 0;
+        let mut last_key: Option<String> = None;
+        let mut opened = false;
+        for line in lines.by_ref() {
+            offset += line.len();
+            let trimmed = line.trim();
+            if trimmed == "-- ---" {
+                if opened {
+                    body_start = offset;
+                    break;
+                }
+                opened = true;
+                continue;
+            }
+            let Some(text) = trimmed.strip_prefix("--") else { break };
+            if let Some(cont) = text.strip_prefix("   ") {
+                // Continuation line of a multi-line value
+                if let Some(k) = &last_key {
+                    let v = fields.entry(k.clone()).or_default();
+                    v.push('\n');
+                    v.push_str(cont.trim());
+                }
+                continue;
+            }
+            if let Some((k, v)) = text.split_once(':') {
+                let k = k.trim().to_lowercase();
+                fields.insert(k.clone(), v.trim().to_string());
+                last_key = Some(k);
+            }
+        }
+    }
+
+    let field = |k: &str| fields.get(k).cloned().filter(|v| !v.is_empty());
+    let now = chrono::Local::now();
+    Snippet {
+        id: field("id").unwrap_or_else(|| format!("{}", now.timestamp_nanos_opt().unwrap_or_default())),
+        name: field("name").unwrap_or_else(|| fallback_name.to_string()),
+        sql: content[body_start..].trim().to_string(),
+        description: field("description"),
+        created_at: field("created_at").unwrap_or_else(|| now.to_rfc3339()),
+        folder: normalize_folder(field("folder").or(fallback_folder).as_deref()),
+        tags: normalize_tags(
+            &field("tags")
+                .unwrap_or_default()
+                .trim_matches(|c| c == '[' || c == ']')
+                .split(',')
+                .map(|t| t.to_string())
+                .collect::<Vec<_>>(),
+        ),
+        server_id: field("server_id"),
+        database: field("database"),
+        favorite: field("favorite").is_some_and(|v| v == "true" || v == "yes"),
+        updated_at: field("updated_at"),
+    }
+}
+
+fn file_slug(name: &str) -> String {
+    let slug: String = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c.to_ascii_lowercase() } else { '-' })
+        .collect();
+    let slug = slug.split('-').filter(|p| !p.is_empty()).collect::<Vec<_>>().join("-");
+    if slug.is_empty() { "snippet".to_string() } else { slug }
+}
+
+fn collect_sql_files(dir: &std::path::Path, out: &mut Vec<PathBuf>) -> Result<(), String> {
+    for entry in fs::read_dir(dir).map_err(|e| e.to_string())? {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        if path.is_dir() {
+            collect_sql_files(&path, out)?;
+        } else if path.extension().is_some_and(|e| e.eq_ignore_ascii_case("sql")) {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Writes snippets (all, or the given ids) to `path`: a JSON bundle for "json",
+/// or one `.sql` file per snippet, nested by folder, for "folder".
+#[tauri::command]
+pub async fn export_snippets(path: String, format: String, ids: Option<Vec<String>>) -> Result<usize, String> {
+    let snippets: Vec<Snippet> = load_snippets()?
+        .into_iter()
+        .filter(|s| ids.as_ref().map_or(true, |ids| ids.contains(&s.id)))
+        .collect();
+
+    if format == "folder" {
+        let root = PathBuf::from(&path);
+        fs::create_dir_all(&root).map_err(|e| e.to_string())?;
+        let mut used: Vec<PathBuf> = Vec::new();
+        for s in &snippets {
+            let mut dir = root.clone();
+            if let Some(f) = &s.folder {
+                for part in f.split('/') {
+                    dir.push(part);
+                }
+            }
+            fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+            let slug = file_slug(&s.name);
+            let mut file = dir.join(format!("{}.sql", slug));
+            if used.contains(&file) {
+                file = dir.join(format!("{}-{}.sql", slug, file_slug(&s.id)));
+            }
+            fs::write(&file, render_front_matter(s)).map_err(|e| e.to_string())?;
+            used.push(file);
+        }
+    } else {
+        let config = SnippetsConfig { snippets: snippets.clone() };
+        let json = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+        fs::write(&path, json).map_err(|e| e.to_string())?;
+    }
+
+    Ok(snippets.len())
+}
+
+#[derive(Serialize, Default)]
+pub struct SnippetImportReport {
+    pub imported: usize,
+    pub updated: usize,
+    pub skipped_duplicates: Vec<String>,
+}
+
+/// Imports a JSON bundle or a folder of `.sql` files. Snippets with a known id are updated;
+/// new snippets whose SQL duplicates an existing one are skipped unless `allow_duplicates` is set.
+#[tauri::command]
+pub async fn import_snippets(path: String, allow_duplicates: Option<bool>) -> Result<SnippetImportReport, String> {
+    let source = PathBuf::from(&path);
+    let incoming: Vec<Snippet> = if source.is_dir() {
+        let mut files = Vec::new();
+        collect_sql_files(&source, &mut files)?;
+        files.sort();
+        let mut out = Vec::new();
+        for file in files {
+            let content = fs::read_to_string(&file).map_err(|e| e.to_string())?;
+            let name = file.file_stem().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+            let folder = file
+                .parent()
+                .and_then(|p| p.strip_prefix(&source).ok())
+                .map(|p| p.to_string_lossy().replace('\\', "/"));
+            out.push(parse_front_matter(&content, &name, folder));
+        }
+        out
+    } else {
+        let content = fs::read_to_string(&source).map_err(|e| e.to_string())?;
+        let config: SnippetsConfig = serde_json::from_str(&content).map_err(|e| format!("Invalid snippet bundle: {}", e))?;
+        config.snippets
+    };
+
+    let mut snippets = load_snippets()?;
+    let mut report = SnippetImportReport::default();
+    for mut s in incoming {
+        s.folder = normalize_folder(s.folder.as_deref());
+        s.tags = normalize_tags(&s.tags);
+        if let Some(pos) = snippets.iter().position(|e| e.id == s.id) {
+            snippets[pos] = s;
+            report.updated += 1;
+            continue;
+        }
+        let key = normalized_sql(&s.sql);
+        if !allow_duplicates.unwrap_or(false) && snippets.iter().any(|e| normalized_sql(&e.sql) == key) {
+            report.skipped_duplicates.push(s.name);
+            continue;
+        }
+        snippets.push(s);
+        report.imported += 1;
+    }
+
+    write_snippets(&snippets)?;
+    Ok(report)
+}
+
+// --- Templated variables ---
+//
+// Snippets may declare variables inline: `{{name}}`, `{{name:type}}` or `{{name:type = default}}`.
+// Types: string, int, float, bool, date, datetime, enum(a, b, c) and enum(SELECT ...).
+// Date defaults accept `today`/`now` with an optional offset such as `today-7d` or `now+2h`.
+
+#[derive(Debug, Serialize, Clone)]
+pub struct SnippetVariable {
+    pub name: String,
+    pub var_type: String,
+    pub default: Option<String>,
+    pub options: Vec<String>,
+    pub options_query: Option<String>,
+    pub resolved_default: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct RenderedSnippet {
+    pub sql: String,
+    pub params: Vec<serde_json::Value>,
+}
+
+struct Placeholder {
+    start: usize,
+    end: usize,
+    variable: SnippetVariable,
+}
+
+fn parse_variable(spec: &str) -> Result<SnippetVariable, String> {
+    // The default starts at the first `=` outside parentheses, so enum queries may contain `=`.
+    let mut depth = 0i32;
+    let eq = spec.char_indices().find(|&(_, c)| {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => {}
+        }
+        c == '=' && depth == 0
+    }).map(|(i, _)| i);
+    let (decl, default) = match eq {
+        Some(pos) => (&spec[..pos], Some(spec[pos + 1..].trim().to_string())),
+        None => (spec, None),
+    };
+    let (name, type_spec) = match decl.find(':') {
+        Some(pos) => (decl[..pos].trim(), decl[pos + 1..].trim()),
+        None => (decl.trim(), "string"),
+    };
+    if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return Err(format!("Invalid variable name in {{{{{}}}}}", spec));
+    }
+
+    let mut options = Vec::new();
+    let mut options_query = None;
+    let lower = type_spec.to_lowercase();
+    let var_type = if lower.starts_with("enum(") && type_spec.ends_with(')') {
+        let inner = type_spec[5..type_spec.len() - 1].trim();
+        if inner.to_uppercase().starts_with("SELECT") {
+            options_query = Some(inner.to_string());
+        } else {
+            options = inner
+                .split(',')
+                .map(|o| o.trim().trim_matches(|c| c == '\'' || c == '"').to_string())
+                .filter(|o| !o.is_empty())
+                .collect();
+        }
+        "enum".to_string()
+    } else {
+        match lower.as_str() {
+            "" | "string" | "text" | "str" => "string".to_string(),
+            "int" | "integer" => "int".to_string(),
+            "float" | "decimal" | "number" => "float".to_string(),
+            "bool" | "boolean" => "bool".to_string(),
+            "date" => "date".to_string(),
+            "datetime" | "timestamp" => "datetime".to_string(),
+            other => return Err(format!("Unknown variable type `{}` for `{}`", other, name)),
+        }
+    };
+
+    let default = default.filter(|d| !d.is_empty()).map(|d| d.trim_matches(|c| c == '\'' || c == '"').to_string());
+    let resolved_default = match &default {
+        Some(d) => Some(resolve_default(&var_type, d)?),
+        None => None,
+    };
+
+    Ok(SnippetVariable {
+        name: name.to_string(),
+        var_type,
+        default,
+        options,
+        options_query,
+        resolved_default,
+    })
+}
+
+/// Expands relative date expressions (`today`, `now-2h`, `today+1w`) to concrete values.
+/// Units are `d`, `w`, `h`, `min` and `s`.
+fn resolve_default(var_type: &str, expr: &str) -> Result<String, String> {
+    if var_type != "date" && var_type != "datetime" {
+        return Ok(expr.to_string());
+    }
+    let lower = expr.to_lowercase().replace(' ', "");
+    let (base, rest) = if let Some(r) = lower.strip_prefix("today") {
+        (chrono::Local::now().date_naive().and_hms_opt(0, 0, 0).unwrap(), r)
+    } else if let Some(r) = lower.strip_prefix("now") {
+        (chrono::Local::now().naive_local(), r)
+    } else {
+        return Ok(expr.to_string());
+    };
+
+    let value = apply_offset(base, rest).ok_or(format!("Invalid date offset in `{}`", expr))?;
+    Ok(if var_type == "date" {
+        value.format("%Y-%m-%d").to_string()
+    } else {
+        value.format("%Y-%m-%d %H:%M:%S").to_string()
+    })
+}
+
+/// Applies an offset of the form `[+-]<digits><unit>` (or nothing) to `base`.
+fn apply_offset(base: chrono::NaiveDateTime, offset: &str) -> Option<chrono::NaiveDateTime> {
+    if offset.is_empty() {
+        return Some(base);
+    }
+    let (negative, rest) = match offset.as_bytes()[0] {
+        b'+' => (false, &offset[1..]),
+        b'-' => (true, &offset[1..]),
+        _ => return None,
+    };
+    let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+    if digits == 0 {
+        return None;
+    }
+    let amount: i64 = rest[..digits].parse().ok()?;
+    let delta = match &rest[digits..] {
+        "d" => chrono::Duration::try_days(amount)?,
+        "w" => chrono::Duration::try_weeks(amount)?,
+        "h" => chrono::Duration::try_hours(amount)?,
+        "min" => chrono::Duration::try_minutes(amount)?,
+        "s" => chrono::Duration::try_seconds(amount)?,
+        _ => return None,
+    };
+    if negative {
+        base.checked_sub_signed(delta)
+    } else {
+        base.checked_add_signed(delta)
+    }
+}
+
+/// Finds `{{...}}` placeholders outside string literals and comments.
+fn find_placeholders(sql: &str) -> Result<Vec<Placeholder>, String> {
+    let bytes = sql.as_bytes();
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            q @ (b'\'' | b'"' | b'`') => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != q {
+                    if bytes[i] == b'\\' && q != b'`' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+                i += 1;
+            }
+            b'#' => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'-' if sql[i..].starts_with("-- ") || sql[i..].starts_with("--\n") => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if sql[i..].starts_with("/*") => {
+                i = sql[i + 2..].find("*/").map(|p| i + p + 4).unwrap_or(bytes.len());
+            }
+            b'{' if sql[i..].starts_with("{{") => {
+                let close = sql[i + 2..].find("}}").ok_or("Unclosed {{ in snippet")?;
+                let spec = &sql[i + 2..i + 2 + close];
+                found.push(Placeholder {
+                    start: i,
+                    end: i + close + 4,
+                    variable: parse_variable(spec.trim())?,
+                });
+                i += close + 4;
+            }
+            _ => i += 1,
+        }
+    }
+    Ok(found)
+}
+
+/// Variables declared in `sql`, one entry per name. Later occurrences may omit the type.
+fn collect_variables(sql: &str) -> Result<Vec<SnippetVariable>, String> {
+    let mut vars: Vec<SnippetVariable> = Vec::new();
+    for p in find_placeholders(sql)? {
+        match vars.iter_mut().find(|v| v.name == p.variable.name) {
+            Some(existing) => {
+                if existing.var_type == "string" && p.variable.var_type != "string" {
+                    *existing = p.variable;
+                } else if existing.default.is_none() && p.variable.default.is_some() {
+                    existing.default = p.variable.default;
+                    existing.resolved_default = p.variable.resolved_default;
+                }
+            }
+            None => vars.push(p.variable),
+        }
+    }
+    Ok(vars)
+}
+
+fn json_to_text(val: &serde_json::Value) -> Option<String> {
+    match val {
+        serde_json::Value::Null => None,
+        serde_json::Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+/// Validates a user-supplied value against the variable type and converts it into a bound parameter.
+fn bind_value(var: &SnippetVariable, raw: Option<String>) -> Result<mysql_async::Value, String> {
+    let text = match raw.or_else(|| var.resolved_default.clone()) {
+        Some(t) => t,
+        None => return Err(format!("Missing value for `{}`", var.name)),
+    };
+    let bad = |what: &str| format!("`{}` expects {}, got `{}`", var.name, what, text);
+    match var.var_type.as_str() {
+        "int" => text.trim().parse::<i64>().map(mysql_async::Value::Int).map_err(|_| bad("an integer")),
+        "float" => text.trim().parse::<f64>().map(mysql_async::Value::Double).map_err(|_| bad("a number")),
+        "bool" => match text.trim().to_lowercase().as_str() {
+            "1" | "true" | "yes" | "on" => Ok(mysql_async::Value::Int(1)),
+            "0" | "false" | "no" | "off" => Ok(mysql_async::Value::Int(0)),
+            _ => Err(bad("a boolean")),
+        },
+        "date" => {
+            use chrono::Datelike;
+            let text = resolve_default("date", text.trim())?;
+            let d = chrono::NaiveDate::parse_from_str(&text, "%Y-%m-%d").map_err(|_| bad("a date (YYYY-MM-DD)"))?;
+            Ok(mysql_async::Value::Date(d.year() as u16, d.month() as u8, d.day() as u8, 0, 0, 0, 0))
+        }
+        "datetime" => {
+            use chrono::{Datelike, Timelike};
+            let text = resolve_default("datetime", text.trim())?;
+            let dt = chrono::NaiveDateTime::parse_from_str(&text, "%Y-%m-%d %H:%M:%S")
+                .or_else(|_| chrono::NaiveDateTime::parse_from_str(&text, "%Y-%m-%dT%H:%M:%S"))
+                .or_else(|_| chrono::NaiveDateTime::parse_from_str(&text, "%Y-%m-%dT%H:%M"))
+                .or_else(|_| chrono::NaiveDate::parse_from_str(&text, "%Y-%m-%d").map(|d| d.and_hms_opt(0, 0, 0).unwrap()))
+                .map_err(|_| bad("a datetime (YYYY-MM-DD HH:MM:SS)"))?;
+            Ok(mysql_async::Value::Date(
+                dt.year() as u16, dt.month() as u8, dt.day() as u8,
+                dt.hour() as u8, dt.minute() as u8, dt.second() as u8, 0,
+            ))
+        }
+        "enum" if !var.options.is_empty() && !var.options.contains(&text) => {
+            Err(format!("`{}` must be one of: {}", var.name, var.options.join(", ")))
+        }
+        _ => Ok(mysql_async::Value::from(text)),
+    }
+}
+
+/// Replaces every placeholder with `?` and returns the bound parameters in order.
+fn render(sql: &str, values: &HashMap<String, serde_json::Value>) -> Result<(String, Vec<mysql_async::Value>), String> {
+    let declared = collect_variables(sql)?;
+    let mut out = String::with_capacity(sql.len());
+    let mut params = Vec::new();
+    let mut errors = Vec::new();
+    let mut last = 0;
+
+    for p in find_placeholders(sql)? {
+        out.push_str(&sql[last..p.start]);
+        out.push('?');
+        last = p.end;
+
+        let var = declared.iter().find(|v| v.name == p.variable.name).unwrap_or(&p.variable);
+        let provided = values.get(&var.name);
+        if provided.is_some_and(|v| v.is_null()) {
+            params.push(mysql_async::Value::NULL);
+            continue;
+        }
+        match bind_value(var, provided.and_then(json_to_text)) {
+            Ok(v) => params.push(v),
+            Err(e) => {
+                if !errors.contains(&e) {
+                    errors.push(e);
+                }
+            }
+        }
+    }
+    out.push_str(&sql[last..]);
+
+    if !errors.is_empty() {
+        return Err(errors.join("; "));
+    }
+    Ok((out, params))
+}
+
+#[tauri::command]
+pub async fn get_snippet_variables(sql: String, db: Option<String>, state: State<'_, AppState>) -> Result<Vec<SnippetVariable>, String> {
+    let mut vars = collect_variables(&sql)?;
+    if vars.iter().all(|v| v.options_query.is_none()) {
+        return Ok(vars);
+    }
+
+    // Enum options backed by a query are only populated when connected.
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned()
+    };
+    if let Some(pool) = pool {
+        let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+        if let Some(db_name) = db.filter(|d| !d.is_empty()) {
+            conn.query_drop(format!("USE `{}`", db_name)).await.map_err(|e| e.to_string())?;
+        }
+        for var in vars.iter_mut() {
+            if let Some(q) = &var.options_query {
+                let rows: Vec<mysql_async::Row> = conn.query(q).await.map_err(|e| format!("Options for `{}`: {}", var.name, e))?;
+                var.options = rows
+                    .into_iter()
+                    .filter_map(|row| row.get::<mysql_async::Value, _>(0))
+                    .filter_map(|v| crate::commands::common::value_to_text(&v))
+                    .collect();
+            }
+        }
+    }
+    Ok(vars)
+}
+
+#[tauri::command]
+pub async fn render_snippet(sql: String, values: HashMap<String, serde_json::Value>) -> Result<RenderedSnippet, String> {
+    let (sql, params) = render(&sql, &values)?;
+    Ok(RenderedSnippet {
+        sql,
+        params: params.into_iter().map(mysql_to_json).collect(),
+    })
+}
+
+/// Executes a templated snippet with its variables bound as prepared-statement parameters.
+#[tauri::command]
+pub async fn execute_snippet(sql: String, values: HashMap<String, serde_json::Value>, db: Option<String>, state: State<'_, AppState>) -> Result<QueryResult, String> {
+    let (rendered, params) = render(&sql, &values)?;
+
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    if let Some(db_name) = db {
+        if !db_name.is_empty() {
+            conn.query_drop(format!("USE `{}`", db_name)).await.map_err(|e| e.to_string())?;
+        }
+    }
+
+    let mut result = conn.exec_iter(rendered.as_str(), params).await.map_err(|e| format!("SQL Error: {}", e))?;
+    let meta: Vec<mysql_async::Column> = result.columns().map(|cols| cols.to_vec()).unwrap_or_default();
+    let rows_data: Vec<mysql_async::Row> = result.collect().await.map_err(|e| e.to_string())?;
+
+    let columns = meta.iter().map(|c| c.name_str().into_owned()).collect();
+    let rows = rows_data
+        .into_iter()
+        .map(|row| {
+            meta.iter()
+                .enumerate()
+                .map(|(i, col)| mysql_to_json(text_protocol_value(row.get(i).unwrap_or(mysql_async::Value::NULL), col)))
+                .collect()
+        })
+        .collect();
+
+    Ok(QueryResult { columns, rows })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base() -> chrono::NaiveDateTime {
+        chrono::NaiveDate::from_ymd_opt(2024, 3, 10).unwrap().and_hms_opt(12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn applies_offsets() {
+        assert_eq!(apply_offset(base(), ""), Some(base()));
+        assert_eq!(apply_offset(base(), "+1d"), Some(base() + chrono::Duration::days(1)));
+        assert_eq!(apply_offset(base(), "-2w"), Some(base() - chrono::Duration::weeks(2)));
+        assert_eq!(apply_offset(base(), "-3h"), Some(base() - chrono::Duration::hours(3)));
+        assert_eq!(apply_offset(base(), "+15min"), Some(base() + chrono::Duration::minutes(15)));
+        assert_eq!(apply_offset(base(), "+30s"), Some(base() + chrono::Duration::seconds(30)));
+    }
+
+    #[test]
+    fn rejects_malformed_offsets() {
+        for offset in ["+", "-", "+d", "+1", "1d", "+1m", "+1x", "+-1d", "é", "+1é", "+99999999999999999999d"] {
+            assert_eq!(apply_offset(base(), offset), None, "{}", offset);
+        }
+    }
+
+    #[test]
+    fn resolve_default_reports_errors_instead_of_panicking() {
+        for expr in ["today+", "now-", "todayé", "now+1dé", "today+1m"] {
+            assert!(resolve_default("date", expr).is_err(), "{}", expr);
+        }
+        assert!(resolve_default("datetime", "now-2h").is_ok());
+        assert_eq!(resolve_default("text", "today+").unwrap(), "today+");
+    }
 }
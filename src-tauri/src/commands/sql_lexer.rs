@@ -0,0 +1,294 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenKind {
+    Whitespace,
+    Word,          // Keywords, identifiers and function names
+    QuotedIdent,   // `backtick quoted`
+    String,        // 'single', "double", plus X'..', N'..', _charset'..' prefixes
+    Number,
+    Variable,      // @user_var, @@system_var
+    Placeholder,   // ?
+    Operator,
+    Comma,
+    Dot,
+    OpenParen,
+    CloseParen,
+    Semicolon,
+    LineComment,   // -- and #
+    BlockComment,  // /* ... */
+    ExecComment,   // /*! ... */ executable comments and /*+ ... */ optimizer hints
+}
+
+#[derive(Debug, Clone)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub text: String,
+    pub start: usize, // Byte offsets into the source
+    pub end: usize,
+}
+
+impl Token {
+    pub fn is_word(&self, word: &str) -> bool {
+        self.kind == TokenKind::Word && self.text.eq_ignore_ascii_case(word)
+    }
+
+    pub fn is_comment(&self) -> bool {
+        matches!(self.kind, TokenKind::LineComment | TokenKind::BlockComment | TokenKind::ExecComment)
+    }
+
+    pub fn upper(&self) -> String {
+        self.text.to_ascii_uppercase()
+    }
+}
+
+// Reserved words plus the clause words the formatter and linter care about.
+// Non-reserved words (NAME, STATUS, DATE...) are deliberately left out so column names keep their case.
+pub const KEYWORDS: &[&str] = &[
+    "ADD", "ALL", "ALTER", "ANALYZE", "AND", "ANY", "AS", "ASC", "BEFORE", "BETWEEN", "BIGINT", "BINARY",
+    "BLOB", "BOTH", "BY", "CALL", "CASCADE", "CASE", "CHANGE", "CHAR", "CHARACTER", "CHECK", "COLLATE",
+    "COLUMN", "CONSTRAINT", "CREATE", "CROSS", "CURRENT_DATE", "CURRENT_TIME", "CURRENT_TIMESTAMP",
+    "CURRENT_USER", "DATABASE", "DATABASES", "DECIMAL", "DECLARE", "DEFAULT", "DELAYED", "DELETE", "DESC",
+    "DESCRIBE", "DISTINCT", "DISTINCTROW", "DIV", "DOUBLE", "DROP", "DUAL", "EACH", "ELSE", "ELSEIF",
+    "ENCLOSED", "ESCAPED", "EXCEPT", "EXISTS", "EXPLAIN", "FALSE", "FLOAT", "FOR", "FORCE", "FOREIGN",
+    "FROM", "FULLTEXT", "GENERATED", "GRANT", "GROUP", "HAVING", "HIGH_PRIORITY", "IF", "IGNORE", "IN",
+    "INDEX", "INFILE", "INNER", "INOUT", "INSERT", "INT", "INTEGER", "INTERSECT", "INTERVAL", "INTO", "IS",
+    "ITERATE", "JOIN", "KEY", "KEYS", "KILL", "LEADING", "LEAVE", "LEFT", "LIKE", "LIMIT", "LINES", "LOAD",
+    "LOCK", "LONGBLOB", "LONGTEXT", "LOOP", "LOW_PRIORITY", "MEDIUMBLOB", "MEDIUMINT", "MEDIUMTEXT", "MOD",
+    "NATURAL", "NOT", "NULL", "NUMERIC", "ON", "OPTIMIZE", "OPTION", "OPTIONALLY", "OR", "ORDER", "OUT",
+    "OUTER", "OUTFILE", "OVER", "PARTITION", "PRIMARY", "PROCEDURE", "RANGE", "READ", "RECURSIVE", "REFERENCES",
+    "REGEXP", "RENAME", "REPEAT", "REPLACE", "REQUIRE", "RESTRICT", "RETURN", "REVOKE", "RIGHT", "RLIKE",
+    "SCHEMA", "SELECT", "SET", "SHOW", "SMALLINT", "SPATIAL", "SQL_BIG_RESULT", "SQL_CALC_FOUND_ROWS",
+    "SQL_SMALL_RESULT", "STARTING", "STORED", "STRAIGHT_JOIN", "TABLE", "TERMINATED", "THEN", "TINYBLOB",
+    "TINYINT", "TINYTEXT", "TO", "TRAILING", "TRIGGER", "TRUE", "UNION", "UNIQUE", "UNLOCK", "UNSIGNED",
+    "UPDATE", "USAGE", "USE", "USING", "VALUES", "VARBINARY", "VARCHAR", "VIRTUAL", "WHEN", "WHERE", "WHILE",
+    "WINDOW", "WITH", "WRITE", "XOR", "ZEROFILL",
+];
+
+// Non-reserved words that only read as keywords inside DDL statements.
+pub const DDL_KEYWORDS: &[&str] = &[
+    "AFTER", "ALGORITHM", "AUTO_INCREMENT", "BIT", "BOOL", "BOOLEAN", "CHARSET", "CHECKSUM", "COMMENT",
+    "COMPRESSED", "DATE", "DATETIME", "DEFINER", "DYNAMIC", "ENGINE", "ENUM", "EVENT", "FIRST", "FIXED",
+    "FUNCTION", "INVOKER", "JSON", "MERGE", "MODIFY", "NO", "ROW_FORMAT", "SECURITY", "SQL", "TEMPORARY",
+    "TEMPTABLE", "TEXT", "TIME", "TIMESTAMP", "UNDEFINED", "VIEW", "YEAR",
+];
+
+// Built-in functions, only treated as keywords when directly followed by an opening parenthesis.
+pub const FUNCTIONS: &[&str] = &[
+    "ABS", "AVG", "CAST", "CEIL", "COALESCE", "CONCAT", "CONCAT_WS", "CONVERT", "COUNT", "DATE", "DATE_ADD",
+    "DATE_FORMAT", "DATE_SUB", "DATEDIFF", "FLOOR", "GREATEST", "GROUP_CONCAT", "IF", "IFNULL", "JSON_EXTRACT",
+    "JSON_OBJECT", "JSON_UNQUOTE", "LEAST", "LENGTH", "LOWER", "LPAD", "MAX", "MIN", "NOW", "NULLIF", "RAND",
+    "ROUND", "ROW_NUMBER", "RPAD", "STR_TO_DATE", "SUBSTRING", "SUM", "TRIM", "UNIX_TIMESTAMP", "UPPER", "YEAR",
+];
+
+pub fn is_keyword(word: &str) -> bool {
+    let upper = word.to_ascii_uppercase();
+    KEYWORDS.contains(&upper.as_str())
+}
+
+pub fn is_ddl_keyword(word: &str) -> bool {
+    let upper = word.to_ascii_uppercase();
+    DDL_KEYWORDS.contains(&upper.as_str())
+}
+
+pub fn is_function(word: &str) -> bool {
+    let upper = word.to_ascii_uppercase();
+    FUNCTIONS.contains(&upper.as_str())
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$' || !c.is_ascii()
+}
+
+const OPERATORS: &[&str] = &[
+    "<=>", "->>", "<=", ">=", "<>", "!=", "||", "&&", ":=", "<<", ">>", "->",
+];
+
+pub fn tokenize(sql: &str) -> Vec<Token> {
+    let chars: Vec<(usize, char)> = sql.char_indices().collect();
+    let len = chars.len();
+    let byte_at = |i: usize| if i < len { chars[i].0 } else { sql.len() };
+    let char_at = |i: usize| if i < len { Some(chars[i].1) } else { None };
+
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < len {
+        let c = chars[i].1;
+        let start = i;
+        let kind;
+
+        if c.is_whitespace() {
+            while i < len && chars[i].1.is_whitespace() {
+                i += 1;
+            }
+            kind = TokenKind::Whitespace;
+        } else if c == '#' || (c == '-' && char_at(i + 1) == Some('-') && char_at(i + 2).map_or(true, |n| n.is_whitespace())) {
+            while i < len && chars[i].1 != '\n' {
+                i += 1;
+            }
+            kind = TokenKind::LineComment;
+        } else if c == '/' && char_at(i + 1) == Some('*') {
+            kind = match char_at(i + 2) {
+                Some('!') | Some('+') => TokenKind::ExecComment,
+                _ => TokenKind::BlockComment,
+            };
+            i += 2;
+            while i < len && !(chars[i].1 == '*' && char_at(i + 1) == Some('/')) {
+                i += 1;
+            }
+            i = (i + 2).min(len);
+        } else if c == '\'' || c == '"' {
+            i = skip_quoted(&chars, i, c);
+            kind = TokenKind::String;
+        } else if c == '`' {
+            i = skip_quoted(&chars, i, '`');
+            kind = TokenKind::QuotedIdent;
+        } else if c.is_ascii_digit() {
+            if c == '0' && matches!(char_at(i + 1), Some('x') | Some('b')) && char_at(i + 2).is_some_and(|n| n.is_ascii_hexdigit()) {
+                i += 2;
+                while i < len && chars[i].1.is_ascii_alphanumeric() {
+                    i += 1;
+                }
+            } else {
+                while i < len && (chars[i].1.is_ascii_digit() || chars[i].1 == '.') {
+                    i += 1;
+                }
+                if matches!(char_at(i), Some('e') | Some('E')) {
+                    let mut j = i + 1;
+                    if matches!(char_at(j), Some('+') | Some('-')) {
+                        j += 1;
+                    }
+                    if char_at(j).is_some_and(|n| n.is_ascii_digit()) {
+                        i = j;
+                        while i < len && chars[i].1.is_ascii_digit() {
+                            i += 1;
+                        }
+                    }
+                }
+            }
+            // Identifiers may start with digits (e.g. `1st_quarter`)
+            if i < len && is_ident_char(chars[i].1) && !chars[i].1.is_ascii_digit() {
+                while i < len && is_ident_char(chars[i].1) {
+                    i += 1;
+                }
+                kind = TokenKind::Word;
+            } else {
+                kind = TokenKind::Number;
+            }
+        } else if c == '@' {
+            i += 1;
+            if char_at(i) == Some('@') {
+                i += 1;
+            }
+            match char_at(i) {
+                Some(q) if q == '\'' || q == '"' || q == '`' => {
+                    i = skip_quoted(&chars, i, q);
+                }
+                _ => {
+                    while i < len && (is_ident_char(chars[i].1) || chars[i].1 == '.') {
+                        i += 1;
+                    }
+                }
+            }
+            kind = TokenKind::Variable;
+        } else if is_ident_char(c) {
+            while i < len && is_ident_char(chars[i].1) {
+                i += 1;
+            }
+            // Introducers and literal prefixes: X'0A', B'01', N'text', _utf8mb4'text'
+            let word = &sql[byte_at(start)..byte_at(i)];
+            let is_prefix = word.eq_ignore_ascii_case("x") || word.eq_ignore_ascii_case("b")
+                || word.eq_ignore_ascii_case("n") || word.starts_with('_');
+            if is_prefix && char_at(i) == Some('\'') {
+                i = skip_quoted(&chars, i, '\'');
+                kind = TokenKind::String;
+            } else {
+                kind = TokenKind::Word;
+            }
+        } else {
+            i += 1;
+            kind = match c {
+                ',' => TokenKind::Comma,
+                '.' => TokenKind::Dot,
+                '(' => TokenKind::OpenParen,
+                ')' => TokenKind::CloseParen,
+                ';' => TokenKind::Semicolon,
+                '?' => TokenKind::Placeholder,
+                _ => {
+                    let rest = &sql[byte_at(start)..];
+                    if let Some(op) = OPERATORS.iter().find(|op| rest.starts_with(**op)) {
+                        i = start + op.chars().count();
+                    }
+                    TokenKind::Operator
+                }
+            };
+        }
+
+        tokens.push(Token {
+            kind,
+            text: sql[byte_at(start)..byte_at(i)].to_string(),
+            start: byte_at(start),
+            end: byte_at(i),
+        });
+    }
+
+    tokens
+}
+
+fn skip_quoted(chars: &[(usize, char)], open: usize, quote: char) -> usize {
+    let mut i = open + 1;
+    while i < chars.len() {
+        let c = chars[i].1;
+        if c == '\\' && quote != '`' {
+            i += 2;
+            continue;
+        }
+        if c == quote {
+            if i + 1 < chars.len() && chars[i + 1].1 == quote {
+                i += 2;
+                continue;
+            }
+            return i + 1;
+        }
+        i += 1;
+    }
+    chars.len()
+}
+
+/// Converts a byte offset into a 1-based (line, column) pair, counting columns in characters.
+pub fn line_col(sql: &str, offset: usize) -> (usize, usize) {
+    let mut line = 1;
+    let mut col = 1;
+    for (idx, c) in sql.char_indices() {
+        if idx >= offset {
+            break;
+        }
+        if c == '\n' {
+            line += 1;
+            col = 1;
+        } else {
+            col += 1;
+        }
+    }
+    (line, col)
+}
+
+/// Splits a token stream into statements on top-level semicolons, dropping the semicolons themselves.
+pub fn split_statements(tokens: &[Token]) -> Vec<Vec<Token>> {
+    let mut statements = Vec::new();
+    let mut current: Vec<Token> = Vec::new();
+    for tok in tokens {
+        if tok.kind == TokenKind::Semicolon {
+            statements.push(std::mem::take(&mut current));
+        } else {
+            current.push(tok.clone());
+        }
+    }
+    if current.iter().any(|t| t.kind != TokenKind::Whitespace) {
+        statements.push(current);
+    }
+    statements.retain(|s| s.iter().any(|t| t.kind != TokenKind::Whitespace));
+    statements
+}
@@ -1,25 +1,34 @@
 use tauri::State;
 use crate::state::AppState;
-use crate::commands::common::{mysql_to_json, render_table_html, render_pagination_html};
+use crate::commands::common::{escape_html, mysql_to_json, quote_identifier, render_table_html, render_pagination_html, text_protocol_value, SqlMode};
+use crate::commands::columns::{check_word, column_definition_sql, ColumnDefinition};
+use crate::commands::rows::{duplicate_warning, identity_condition, load_row_key, Condition};
+use crate::commands::values::{json_param, load_column_meta, to_param};
 use mysql_async::prelude::*;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Serialize)]
 pub struct BrowseResultRaw {
     pub columns: Vec<String>,
     pub rows: Vec<Vec<serde_json::Value>>,
     pub total_rows: u64,
+    pub total_is_estimate: bool,
     pub primary_key: Option<String>,
+    pub key_columns: Vec<String>,
+    pub key_kind: String, // "primary", "unique" or "none"
 }
 
 #[derive(Serialize)]
 pub struct TableInfo {
     pub name: String,
+    pub table_type: String, // "BASE TABLE", "VIEW", "SYSTEM VIEW" or "SEQUENCE"
     pub rows: u64,
     pub engine: String,
     pub collation: String,
     pub size: u64, 
     pub overhead: u64, 
+    pub comment: String,
 }
 
 #[derive(Serialize)]
@@ -37,6 +46,9 @@ pub struct ColumnInfo {
     pub key: String,
     pub default: Option<String>,
     pub extra: String,
+    pub comment: String,
+    pub generation_expression: Option<String>,
+    pub generated_kind: Option<String>, // "VIRTUAL" or "STORED"
 }
 
 #[derive(Serialize)]
@@ -48,6 +60,188 @@ pub struct IndexInfo {
     pub is_primary: bool,
 }
 
+#[derive(Deserialize, Clone)]
+pub struct SortSpec {
+    pub column: String,
+    pub direction: Option<String>, // "asc" (default) or "desc"
+    pub nulls: Option<String>,     // "first" or "last"; MySQL default when omitted
+}
+
+#[derive(Deserialize, Clone)]
+pub struct FilterCondition {
+    pub column: String,
+    pub operator: String, // =, !=, <, <=, >, >=, LIKE, NOT LIKE, IN, NOT IN, BETWEEN, IS NULL, IS NOT NULL, REGEXP
+    pub value: Option<serde_json::Value>,
+    pub values: Option<Vec<serde_json::Value>>, // IN / NOT IN lists, BETWEEN bounds
+}
+
+#[derive(Deserialize, Default, Clone)]
+pub struct BrowseOptions {
+    pub sort: Option<Vec<SortSpec>>,
+    pub filters: Option<Vec<FilterCondition>>,
+    pub count_mode: Option<String>, // "exact" (default), "estimate" or "none"
+}
+
+fn filter_sql(filter: &FilterCondition) -> Result<Condition, String> {
+    let col = quote_identifier(&filter.column);
+    let op = filter.operator.trim().to_uppercase();
+    let value = || filter.value.as_ref().ok_or(format!("Filter on `{}` needs a value", filter.column));
+    let values = || filter.values.as_ref().filter(|v| !v.is_empty()).ok_or(format!("Filter on `{}` needs a list of values", filter.column));
+
+    let (sql, params) = match op.as_str() {
+        "=" | "!=" | "<>" | "<" | "<=" | ">" | ">=" => {
+            let v = value()?;
+            if v.is_null() {
+                // `= NULL` never matches; treat it as the intended null test.
+                match op.as_str() {
+                    "=" => (format!("{} IS NULL", col), Vec::new()),
+                    "!=" | "<>" => (format!("{} IS NOT NULL", col), Vec::new()),
+                    _ => return Err(format!("Cannot compare `{}` with NULL using {}", filter.column, op)),
+                }
+            } else {
+                (format!("{} {} ?", col, if op == "<>" { "!=" } else { op.as_str() }), vec![json_param(v)])
+            }
+        }
+        "LIKE" | "NOT LIKE" | "REGEXP" | "NOT REGEXP" => (format!("{} {} ?", col, op), vec![json_param(value()?)]),
+        "IN" | "NOT IN" => {
+            let list = values()?;
+            let placeholders = vec!["?"; list.len()].join(", ");
+            (format!("{} {} ({})", col, op, placeholders), list.iter().map(json_param).collect())
+        }
+        "BETWEEN" => {
+            let bounds = values()?;
+            if bounds.len() != 2 {
+                return Err(format!("BETWEEN on `{}` needs exactly two values", filter.column));
+            }
+            (format!("{} BETWEEN ? AND ?", col), vec![json_param(&bounds[0]), json_param(&bounds[1])])
+        }
+        "IS NULL" | "IS NOT NULL" => (format!("{} {}", col, op), Vec::new()),
+        _ => return Err(format!("Unsupported filter operator: {}", filter.operator)),
+    };
+    Ok(Condition { sql, params })
+}
+
+/// Builds the filter condition (empty when unfiltered) and ORDER BY clause for browsing.
+/// Column names are checked against the table so a stale or hand-crafted spec cannot
+/// inject SQL; filter values are bound as parameters.
+async fn browse_clauses(
+    conn: &mut mysql_async::Conn,
+    db: &str,
+    table: &str,
+    options: &BrowseOptions,
+    default_order: &[String],
+) -> Result<(Condition, String), String> {
+    let sort = options.sort.clone().unwrap_or_default();
+    let filters = options.filters.clone().unwrap_or_default();
+
+    if !sort.is_empty() || !filters.is_empty() {
+        let table_cols: Vec<String> = conn
+            .exec("SELECT COLUMN_NAME FROM information_schema.COLUMNS WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ?", (db, table))
+            .await
+            .map_err(|e| e.to_string())?;
+        for name in sort.iter().map(|s| &s.column).chain(filters.iter().map(|f| &f.column)) {
+            if !table_cols.iter().any(|c| c == name) {
+                return Err(format!("Unknown column `{}`", name));
+            }
+        }
+    }
+
+    let mut conditions = Vec::new();
+    let mut params = Vec::new();
+    for f in &filters {
+        let condition = filter_sql(f)?;
+        conditions.push(condition.sql);
+        params.extend(condition.params);
+    }
+    let filter = Condition { sql: conditions.join(" AND "), params };
+
+    let mut order_parts = Vec::new();
+    for spec in &sort {
+        let col = quote_identifier(&spec.column);
+        let dir = match spec.direction.as_deref().map(|d| d.to_lowercase()) {
+            Some(d) if d == "desc" => "DESC",
+            _ => "ASC",
+        };
+        // MySQL has no NULLS FIRST/LAST; sort on the null test first to emulate it.
+        match spec.nulls.as_deref().map(|n| n.to_lowercase()).as_deref() {
+            Some("first") => order_parts.push(format!("{} IS NULL DESC", col)),
+            Some("last") => order_parts.push(format!("{} IS NULL ASC", col)),
+            _ => {}
+        }
+        order_parts.push(format!("{} {}", col, dir));
+    }
+    if order_parts.is_empty() {
+        order_parts = default_order.iter().map(|c| format!("{} ASC", quote_identifier(c))).collect();
+    }
+    let order_clause = if order_parts.is_empty() {
+        String::new()
+    } else {
+        format!("ORDER BY {}", order_parts.join(", "))
+    };
+
+    Ok((filter, order_clause))
+}
+
+fn where_clause(filter: &Condition) -> String {
+    if filter.sql.is_empty() {
+        String::new()
+    } else {
+        format!("WHERE {}", filter.sql)
+    }
+}
+
+/// Row count for browsing. "estimate" reads `information_schema.TABLES.TABLE_ROWS`, which
+/// is instant but approximate for InnoDB; it only applies to unfiltered browsing.
+async fn browse_count(
+    conn: &mut mysql_async::Conn,
+    db: &str,
+    table: &str,
+    filter: &Condition,
+    count_mode: Option<&str>,
+) -> Result<(Option<u64>, bool), String> {
+    match count_mode.unwrap_or("exact") {
+        "none" => Ok((None, false)),
+        "estimate" if filter.sql.is_empty() => {
+            let estimate: Option<Option<u64>> = conn
+                .exec_first("SELECT TABLE_ROWS FROM information_schema.TABLES WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ?", (db, table))
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok((Some(estimate.flatten().unwrap_or(0)), true))
+        }
+        _ => {
+            let count: Option<u64> = conn
+                .exec_first(format!("SELECT count(*) FROM `{}`.`{}` {}", db, table, where_clause(filter)), filter.params.clone())
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok((Some(count.unwrap_or(0)), false))
+        }
+    }
+}
+
+/// Runs a browse query with bound parameters. Prepared statements return binary values, so
+/// they are rendered back to the text protocol's form to keep the grid unchanged.
+async fn fetch_rows(
+    conn: &mut mysql_async::Conn,
+    sql: String,
+    params: Vec<mysql_async::Value>,
+) -> Result<(Vec<String>, Vec<Vec<serde_json::Value>>), String> {
+    let mut result = conn.exec_iter(sql, params).await.map_err(|e| e.to_string())?;
+    let meta: Vec<mysql_async::Column> = result.columns().map(|cols| cols.to_vec()).unwrap_or_default();
+    let rows_data: Vec<mysql_async::Row> = result.collect().await.map_err(|e| e.to_string())?;
+
+    let columns = meta.iter().map(|c| c.name_str().into_owned()).collect();
+    let rows = rows_data
+        .into_iter()
+        .map(|row| {
+            meta.iter()
+                .enumerate()
+                .map(|(i, col)| mysql_to_json(text_protocol_value(row.get(i).unwrap_or(mysql_async::Value::NULL), col)))
+                .collect()
+        })
+        .collect();
+    Ok((columns, rows))
+}
+
 // Re-using QueryResultHtml from query module or redefining? 
 // Let's redefine or import if we make it public in query (we did).
 use crate::commands::query::QueryResultHtml;
@@ -64,16 +258,23 @@ pub async fn get_tables(db: String, state: State<'_, AppState>) -> Result<Vec<Ta
     
     let mut result = conn.query_iter("SHOW TABLE STATUS").await.map_err(|e| e.to_string())?;
     let rows: Vec<mysql_async::Row> = result.collect().await.map_err(|e| e.to_string())?;
+
+    // SHOW TABLE STATUS has no type column; views only show up with an empty engine.
+    let types: HashMap<String, String> = conn.query("SHOW FULL TABLES").await.map_err(|e| e.to_string())?.into_iter().collect();
     
     let mut tables = Vec::new();
     for row in rows {
+        let name = row.get::<Option<String>, _>("Name").flatten().unwrap_or_default();
         tables.push(TableInfo {
-            name: row.get::<Option<String>, _>("Name").flatten().unwrap_or_default(),
+            table_type: types.get(&name).cloned().unwrap_or_else(|| "BASE TABLE".to_string()),
+            name,
             rows: row.get::<Option<u64>, _>("Rows").flatten().unwrap_or(0),
             engine: row.get::<Option<String>, _>("Engine").flatten().unwrap_or_default(),
             collation: row.get::<Option<String>, _>("Collation").flatten().unwrap_or_default(),
             size: row.get::<Option<u64>, _>("Data_length").flatten().unwrap_or(0) + row.get::<Option<u64>, _>("Index_length").flatten().unwrap_or(0),
             overhead: row.get::<Option<u64>, _>("Data_free").flatten().unwrap_or(0),
+            // Views report the literal "VIEW" here.
+            comment: row.get::<Option<String>, _>("Comment").flatten().filter(|c| c != "VIEW").unwrap_or_default(),
         });
     }
     
@@ -95,24 +296,31 @@ fn render_structure_html(tables: &[TableInfo]) -> String {
         ));
         
         // Name
-        body.push_str(&format!("<td class=\"px-4 py-2.5 border-b border-white/5 font-semibold text-accent\">{}</td>", table.name));
+        let is_view = table.table_type.contains("VIEW");
+        let badge = if is_view { " <span class=\"text-[10px] uppercase opacity-50 font-normal ml-1\">view</span>" } else { "" };
+        body.push_str(&format!("<td class=\"px-4 py-2.5 border-b border-white/5 font-semibold text-accent\" title=\"{}\">{}{}</td>", escape_html(&table.comment), table.name, badge));
         
-        // Actions
+        // Actions (views cannot be emptied)
+        let empty_action = if is_view {
+            String::new()
+        } else {
+            format!("<a href=\"#\" class=\"pma-action-icon empty text-orange-400\" title=\"Empty\" onclick=\"confirmEmptyTable('{}'); return false;\"><i data-lucide=\"eraser\" style=\"width:14px;height:14px;\"></i></a>", table.name)
+        };
         body.push_str(&format!("<td class=\"px-4 py-2.5 border-b border-white/5\">\
             <div class=\"flex gap-2\">\
                 <a href=\"#\" class=\"pma-action-icon browse\" title=\"Browse\" onclick=\"openTable('{}', 'browse'); return false;\"><i data-lucide=\"book-open-check\" style=\"width:14px;height:14px;\"></i></a>\
                 <a href=\"#\" class=\"pma-action-icon search\" title=\"Search\" onclick=\"openTable('{}', 'search'); return false;\"><i data-lucide=\"search\" style=\"width:14px;height:14px;\"></i></a>\
                 <a href=\"#\" class=\"pma-action-icon drop text-red-400\" title=\"Drop\" onclick=\"confirmDropTable('{}'); return false;\"><i data-lucide=\"trash-2\" style=\"width:14px;height:14px;\"></i></a>\
-                <a href=\"#\" class=\"pma-action-icon empty text-orange-400\" title=\"Empty\" onclick=\"confirmEmptyTable('{}'); return false;\"><i data-lucide=\"eraser\" style=\"width:14px;height:14px;\"></i></a>\
+                {}\
             </div>\
-        </td>", table.name, table.name, table.name, table.name));
+        </td>", table.name, table.name, table.name, empty_action));
         
         // Metrics
         body.push_str(&format!("<td class=\"px-4 py-2.5 border-b border-white/5 text-right font-mono text-xs opacity-80\">{}</td>", 
             table.rows));
             
         body.push_str(&format!("<td class=\"px-4 py-2.5 border-b border-white/5 text-xs opacity-60 uppercase\">{}</td>", 
-            table.engine));
+            if is_view { "View" } else { table.engine.as_str() }));
             
         body.push_str(&format!("<td class=\"px-4 py-2.5 border-b border-white/5 text-xs opacity-60\">{}</td>", 
             table.collation));
@@ -159,7 +367,8 @@ fn render_detailed_structure_html(columns: &[ColumnInfo], indexes: &[crate::comm
 
         html.push_str("<tr class=\"hover:bg-white/5 transition-colors\">");
         html.push_str(&format!("<td class=\"px-4 py-2 opacity-50 font-mono text-xs\">{}</td>", i+1));
-        html.push_str(&format!("<td class=\"px-4 py-2 font-bold\">{}{}</td>", key_icon, col.field));
+        let comment = if col.comment.is_empty() { String::new() } else { format!("<div class=\"text-xs font-normal opacity-50\">{}</div>", escape_html(&col.comment)) };
+        html.push_str(&format!("<td class=\"px-4 py-2 font-bold\">{}{}{}</td>", key_icon, col.field, comment));
         html.push_str(&format!("<td class=\"px-4 py-2 text-accent font-mono text-xs\">{}</td>", col.data_type));
         html.push_str(&format!("<td class=\"px-4 py-2 text-xs opacity-70\">{}</td>", col.collation.as_deref().unwrap_or("-")));
         html.push_str(&format!("<td class=\"px-4 py-2 text-xs\">{}</td>", col.null));
@@ -273,9 +482,10 @@ pub async fn get_tables_html(db: String, table: Option<String>, state: State<'_,
 }
 
 #[tauri::command]
-pub async fn browse_table_html(db: String, table: String, page: u32, limit: u32, state: State<'_, AppState>) -> Result<QueryResultHtml, String> {
+pub async fn browse_table_html(db: String, table: String, page: u32, limit: u32, options: Option<BrowseOptions>, state: State<'_, AppState>) -> Result<QueryResultHtml, String> {
     let start = std::time::Instant::now();
     let offset = (page - 1) * limit;
+    let options = options.unwrap_or_default();
     
     let pool = {
         let pool_guard = state.pool.lock().unwrap();
@@ -283,43 +493,18 @@ pub async fn browse_table_html(db: String, table: String, page: u32, limit: u32,
     };
     let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
     
-    // 1. Get Count
-    let count_sql = format!("SELECT count(*) FROM `{}`.`{}`", db, table);
-    let count: Option<u64> = conn.query_first(count_sql).await.map_err(|e| e.to_string())?;
-    let total_rows = count.unwrap_or(0);
+    // 1. Get row identity (all primary key columns, or a NOT NULL unique index)
+    let row_key = load_row_key(&mut conn, &db, &table).await?;
+    let default_order: Vec<String> = if row_key.kind == "none" { Vec::new() } else { row_key.columns.clone() };
+    let (filter, order_by) = browse_clauses(&mut conn, &db, &table, &options, &default_order).await?;
 
-    // 2. Get Primary Key
-    let pk_query = format!("SHOW KEYS FROM `{}`.`{}` WHERE Key_name = 'PRIMARY'", db, table);
-    let pk_row: Option<mysql_async::Row> = conn.query_first(pk_query).await.map_err(|e| e.to_string())?;
-    let order_by = if let Some(row) = pk_row {
-        let col_name: String = row.get("Column_name").unwrap_or_default();
-        format!("ORDER BY `{}` ASC", col_name)
-    } else {
-        "".to_string()
-    };
+    // 2. Get Count
+    let (count, _) = browse_count(&mut conn, &db, &table, &filter, options.count_mode.as_deref()).await?;
+    let total_rows = count.unwrap_or(0);
 
     // 3. Get Data
-    let sql = format!("SELECT * FROM `{}`.`{}` {} LIMIT {} OFFSET {}", db, table, order_by, limit, offset);
-    let mut result = conn.query_iter(sql).await.map_err(|e| e.to_string())?;
-    
-    let mut columns = Vec::new();
-    if let Some(col_slice) = result.columns() {
-        for col in col_slice.iter() {
-            columns.push(col.name_str().into_owned());
-        }
-    }
-
-    let rows_data: Vec<mysql_async::Row> = result.collect().await.map_err(|e| e.to_string())?;
-    let mut final_rows = Vec::new();
-
-    for row in rows_data {
-        let mut row_values = Vec::new();
-        for i in 0..columns.len() {
-            let val: mysql_async::Value = row.get(i).unwrap_or(mysql_async::Value::NULL);
-            row_values.push(mysql_to_json(val));
-        }
-        final_rows.push(row_values);
-    }
+    let sql = format!("SELECT * FROM `{}`.`{}` {} {} LIMIT {} OFFSET {}", db, table, where_clause(&filter), order_by, limit, offset);
+    let (columns, final_rows) = fetch_rows(&mut conn, sql, filter.params).await?;
 
     let duration = start.elapsed().as_secs_f64();
     
@@ -337,8 +522,9 @@ pub async fn browse_table_html(db: String, table: String, page: u32, limit: u32,
 }
 
 #[tauri::command]
-pub async fn browse_table(db: String, table: String, page: u32, limit: u32, state: State<'_, AppState>) -> Result<BrowseResultRaw, String> {
+pub async fn browse_table(db: String, table: String, page: u32, limit: u32, options: Option<BrowseOptions>, state: State<'_, AppState>) -> Result<BrowseResultRaw, String> {
     let offset = (page - 1) * limit;
+    let options = options.unwrap_or_default();
     
     let pool = {
         let pool_guard = state.pool.lock().unwrap();
@@ -346,88 +532,281 @@ pub async fn browse_table(db: String, table: String, page: u32, limit: u32, stat
     };
     let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
     
-    // 1. Get Count
-    let count_sql = format!("SELECT count(*) FROM `{}`.`{}`", db, table);
-    let count: Option<u64> = conn.query_first(count_sql).await.map_err(|e| e.to_string())?;
-    let total_rows = count.unwrap_or(0);
+    // 1. Get row identity for default sorting and editing
+    let row_key = load_row_key(&mut conn, &db, &table).await?;
+    let default_order: Vec<String> = if row_key.kind == "none" { Vec::new() } else { row_key.columns.clone() };
+    let (filter, order_by) = browse_clauses(&mut conn, &db, &table, &options, &default_order).await?;
 
-    // 2. Get Primary Key for sorting
-    let pk_query = format!("SHOW KEYS FROM `{}`.`{}` WHERE Key_name = 'PRIMARY'", db, table);
-    let pk_row: Option<mysql_async::Row> = conn.query_first(pk_query).await.map_err(|e| e.to_string())?;
-    
-    let pk_col = pk_row.map(|row| row.get::<String, _>("Column_name").unwrap_or_default());
-    
-    let order_by = if let Some(ref col_name) = pk_col {
-        format!("ORDER BY `{}` ASC", col_name)
-    } else {
-        "".to_string()
-    };
+    // 2. Get Count (respects filters)
+    let (count, total_is_estimate) = browse_count(&mut conn, &db, &table, &filter, options.count_mode.as_deref()).await?;
+    let total_rows = count.unwrap_or(0);
 
     // 3. Get Data
-    let sql = format!("SELECT * FROM `{}`.`{}` {} LIMIT {} OFFSET {}", db, table, order_by, limit, offset);
-    let mut result = conn.query_iter(sql).await.map_err(|e| e.to_string())?;
+    let sql = format!("SELECT * FROM `{}`.`{}` {} {} LIMIT {} OFFSET {}", db, table, where_clause(&filter), order_by, limit, offset);
+    let (columns, rows) = fetch_rows(&mut conn, sql, filter.params).await?;
     
-    let mut columns = Vec::new();
-    if let Some(col_slice) = result.columns() {
-        for col in col_slice.iter() {
-            columns.push(col.name_str().into_owned());
+    Ok(BrowseResultRaw {
+        columns,
+        rows,
+        total_rows,
+        total_is_estimate,
+        primary_key: if row_key.kind == "none" { None } else { row_key.columns.first().cloned() },
+        key_columns: row_key.columns,
+        key_kind: row_key.kind,
+    })
+}
+
+#[derive(Deserialize, Default)]
+pub struct KeysetOptions {
+    pub key_columns: Option<Vec<String>>, // defaults to the primary key, then a NOT NULL unique index
+    pub direction: Option<String>,        // "first" (default), "next", "prev", "last", "jump"
+    pub cursor: Option<Vec<serde_json::Value>>, // key of the boundary row, or the target key for "jump"
+    pub filters: Option<Vec<FilterCondition>>,
+    pub count_mode: Option<String>,       // "exact", "estimate" (default) or "none"
+}
+
+#[derive(Serialize)]
+pub struct KeysetPage {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<serde_json::Value>>,
+    pub key_columns: Vec<String>,
+    pub first_key: Option<Vec<serde_json::Value>>,
+    pub last_key: Option<Vec<serde_json::Value>>,
+    pub has_next: bool,
+    pub has_prev: bool,
+    pub total_rows: Option<u64>,
+    pub total_is_estimate: bool,
+}
+
+fn key_param(val: &serde_json::Value) -> Result<mysql_async::Value, String> {
+    if let Some(hex) = val.get("$hex").and_then(|h| h.as_str()) {
+        if hex.is_empty() || hex.len() % 2 != 0 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err("Invalid cursor".to_string());
         }
     }
+    Ok(json_param(val))
+}
 
-    let rows_data: Vec<mysql_async::Row> = result.collect().await.map_err(|e| e.to_string())?;
-    let mut rows = Vec::new();
+/// Expands a row comparison such as `(a, b) > (x, y)` into `a > x OR (a = x AND b > y)`,
+/// which every MySQL version can serve from the index.
+fn keyset_condition(columns: &[String], values: &[serde_json::Value], op: &str) -> Result<Condition, String> {
+    if columns.len() != values.len() {
+        return Err(format!("Cursor must have {} value(s)", columns.len()));
+    }
+    let strict = op.trim_end_matches('=');
+    let mut branches = Vec::new();
+    let mut params = Vec::new();
+    for i in 0..columns.len() {
+        let mut parts = Vec::new();
+        for j in 0..i {
+            parts.push(format!("{} = ?", quote_identifier(&columns[j])));
+            params.push(key_param(&values[j])?);
+        }
+        let last_op = if i == columns.len() - 1 { op } else { strict };
+        parts.push(format!("{} {} ?", quote_identifier(&columns[i]), last_op));
+        params.push(key_param(&values[i])?);
+        branches.push(format!("({})", parts.join(" AND ")));
+    }
+    Ok(Condition { sql: format!("({})", branches.join(" OR ")), params })
+}
+
+/// Cursor-based browsing: pages are read with `WHERE key > cursor ORDER BY key LIMIT n`,
+/// so deep pages cost the same as the first one.
+#[tauri::command]
+pub async fn browse_table_keyset(db: String, table: String, limit: u32, options: Option<KeysetOptions>, state: State<'_, AppState>) -> Result<KeysetPage, String> {
+    let opts = options.unwrap_or_default();
+    let limit = limit.max(1);
 
-    for row in rows_data {
-        let mut row_values = Vec::new();
-        for i in 0..columns.len() {
-            let val: mysql_async::Value = row.get(i).unwrap_or(mysql_async::Value::NULL);
-            row_values.push(mysql_to_json(val));
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let key_columns = match opts.key_columns.clone().filter(|k| !k.is_empty()) {
+        Some(cols) => cols,
+        None => {
+            let key = load_row_key(&mut conn, &db, &table).await?;
+            if key.kind == "none" {
+                return Err("Table has no primary key or NOT NULL unique index; use offset browsing instead".to_string());
+            }
+            key.columns
         }
-        rows.push(row_values);
+    };
+
+    // Reuse the browse filter builder; it also validates the key column names.
+    let browse_opts = BrowseOptions {
+        sort: Some(key_columns.iter().map(|c| SortSpec { column: c.clone(), direction: None, nulls: None }).collect()),
+        filters: opts.filters.clone(),
+        count_mode: None,
+    };
+    let (filter, _) = browse_clauses(&mut conn, &db, &table, &browse_opts, &[]).await?;
+
+    let direction = opts.direction.clone().unwrap_or_else(|| "first".to_string());
+    let cursor = opts.cursor.clone().unwrap_or_default();
+    let (key_op, descending) = match direction.as_str() {
+        "next" => (Some(">"), false),
+        "prev" => (Some("<"), true),
+        "jump" => (Some(">="), false),
+        "last" => (None, true),
+        _ => (None, false),
+    };
+    if key_op.is_some() && cursor.is_empty() {
+        return Err(format!("A cursor is required for \"{}\"", direction));
     }
-    
-    Ok(BrowseResultRaw {
+
+    let mut conditions = Vec::new();
+    let mut params = filter.params.clone();
+    if !filter.sql.is_empty() {
+        conditions.push(format!("({})", filter.sql));
+    }
+    if let Some(op) = key_op {
+        let condition = keyset_condition(&key_columns, &cursor, op)?;
+        conditions.push(condition.sql);
+        params.extend(condition.params);
+    }
+    let where_sql = if conditions.is_empty() { String::new() } else { format!("WHERE {}", conditions.join(" AND ")) };
+    let order = |desc: bool| {
+        key_columns
+            .iter()
+            .map(|c| format!("{} {}", quote_identifier(c), if desc { "DESC" } else { "ASC" }))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    // One extra row tells us whether there is another page in the reading direction.
+    let sql = format!(
+        "SELECT * FROM `{}`.`{}` {} ORDER BY {} LIMIT {}",
+        db, table, where_sql, order(descending), limit + 1
+    );
+    let (columns, mut rows) = fetch_rows(&mut conn, sql, params).await?;
+
+    let more_in_direction = rows.len() > limit as usize;
+    rows.truncate(limit as usize);
+    if descending {
+        rows.reverse();
+    }
+
+    let key_idx: Vec<usize> = key_columns
+        .iter()
+        .map(|k| columns.iter().position(|c| c == k).ok_or(format!("Key column `{}` not in result", k)))
+        .collect::<Result<_, _>>()?;
+    let row_key = |row: &Vec<serde_json::Value>| -> Vec<serde_json::Value> { key_idx.iter().map(|&i| row[i].clone()).collect() };
+    let first_key = rows.first().map(row_key);
+    let last_key = rows.last().map(row_key);
+
+    // Check the opposite direction with a cheap index probe.
+    let exists_beyond = |key: &Option<Vec<serde_json::Value>>, op: &str| -> Result<Option<Condition>, String> {
+        let Some(key) = key else { return Ok(None) };
+        let mut conds = Vec::new();
+        let mut params = filter.params.clone();
+        if !filter.sql.is_empty() {
+            conds.push(format!("({})", filter.sql));
+        }
+        let condition = keyset_condition(&key_columns, key, op)?;
+        conds.push(condition.sql);
+        params.extend(condition.params);
+        let sql = format!("SELECT 1 FROM `{}`.`{}` WHERE {} LIMIT 1", db, table, conds.join(" AND "));
+        Ok(Some(Condition { sql, params }))
+    };
+    let (has_next, has_prev) = if descending {
+        let probe = exists_beyond(&last_key, ">")?;
+        let has_next = match probe {
+            Some(q) => conn.exec_first::<u8, _, _>(q.sql, q.params).await.map_err(|e| e.to_string())?.is_some(),
+            None => false,
+        };
+        (has_next, more_in_direction)
+    } else {
+        let probe = exists_beyond(&first_key, "<")?;
+        let has_prev = match probe {
+            Some(q) => conn.exec_first::<u8, _, _>(q.sql, q.params).await.map_err(|e| e.to_string())?.is_some(),
+            None => false,
+        };
+        (more_in_direction, has_prev)
+    };
+
+    let (total_rows, total_is_estimate) = browse_count(
+        &mut conn, &db, &table, &filter, Some(opts.count_mode.as_deref().unwrap_or("estimate")),
+    ).await?;
+
+    Ok(KeysetPage {
         columns,
         rows,
+        key_columns,
+        first_key,
+        last_key,
+        has_next,
+        has_prev,
         total_rows,
-        primary_key: pk_col,
+        total_is_estimate,
     })
 }
 
+#[derive(Serialize)]
+pub struct CellUpdateResult {
+    pub affected_rows: u64,
+    pub warning: Option<String>,
+}
+
+/// Updates one cell. The row is identified by `row` (column -> value as last seen by the
+/// client): all primary key columns, else a NOT NULL unique index, else every column
+/// with `LIMIT 1`. `primary_key_col`/`primary_key_val` remain supported for single-column keys.
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn update_cell(
     db: String, 
     table: String, 
     column: String, 
     value: serde_json::Value, 
-    primary_key_col: String,
-    primary_key_val: serde_json::Value,
+    primary_key_col: Option<String>,
+    primary_key_val: Option<serde_json::Value>,
+    row: Option<HashMap<String, serde_json::Value>>,
     state: State<'_, AppState>
-) -> Result<(), String> {
+) -> Result<CellUpdateResult, String> {
     let pool = {
         let pool_guard = state.pool.lock().unwrap();
         pool_guard.as_ref().cloned().ok_or("Not connected")?
     };
     let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
 
-    // Determine value representation (escape if string)
-    let val_str = match value {
-        serde_json::Value::String(s) => format!("'{}'", s.replace("'", "''")),
-        serde_json::Value::Null => "NULL".to_string(),
-        _ => value.to_string(),
-    };
+    let mut row = row.unwrap_or_default();
+    if let (Some(col), Some(val)) = (primary_key_col, primary_key_val) {
+        row.entry(col).or_insert(val);
+    }
 
-    let pk_val_str = match primary_key_val {
-        serde_json::Value::String(s) => format!("'{}'", s.replace("'", "''")),
-        _ => primary_key_val.to_string(),
-    };
+    let columns = load_column_meta(&mut conn, &db, &table).await?;
+    let col = columns.iter().find(|c| c.name == column).ok_or(format!("Unknown column `{}`", column))?;
+    let param = to_param(col, &value).map_err(|e| format!("`{}` {}", column, e))?;
+
+    let key = load_row_key(&mut conn, &db, &table).await?;
+    let condition = identity_condition(&key, &row)?;
+    let warning = duplicate_warning(&mut conn, &db, &table, &key, &condition).await?;
+    let limit = if key.kind == "none" { " LIMIT 1" } else { "" };
 
     let sql = format!(
-        "UPDATE `{}`.`{}` SET `{}` = {} WHERE `{}` = {}", 
-        db, table, column, val_str, primary_key_col, pk_val_str
+        "UPDATE `{}`.`{}` SET {} = ? WHERE {}{}", 
+        db, table, quote_identifier(&column), condition.sql, limit
     );
+    let mut params = vec![param];
+    params.extend(condition.params);
+
+    conn.exec_drop(sql, params).await.map_err(|e| e.to_string())?;
+    let affected_rows = conn.affected_rows();
+    if affected_rows == 0 {
+        // MySQL reports 0 for rows updated to their current value, so confirm the row still exists.
+        let mut updated = row.clone();
+        updated.insert(column.clone(), value.clone());
+        let check = identity_condition(&key, &updated)?;
+        let still_there: Option<u8> = conn
+            .exec_first(format!("SELECT 1 FROM `{}`.`{}` WHERE {} LIMIT 1", db, table, check.sql), check.params)
+            .await
+            .map_err(|e| e.to_string())?;
+        if still_there.is_none() {
+            return Err("Row not found; it may have been changed or deleted by someone else".to_string());
+        }
+    }
 
-    conn.query_drop(sql).await.map_err(|e| e.to_string())
+    Ok(CellUpdateResult { affected_rows, warning })
 }
 
 #[tauri::command]
@@ -444,16 +823,39 @@ pub async fn get_columns(db: String, table: String, state: State<'_, AppState>)
     let mut result = conn.query_iter(query).await.map_err(|e| e.to_string())?;
     let rows: Vec<mysql_async::Row> = result.collect().await.map_err(|e| e.to_string())?;
     
+    // SHOW FULL COLUMNS only hints at generated columns in Extra; the expression lives here.
+    let generated: HashMap<String, String> = conn
+        .exec(
+            "SELECT COLUMN_NAME, GENERATION_EXPRESSION FROM information_schema.COLUMNS
+             WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ? AND GENERATION_EXPRESSION IS NOT NULL AND GENERATION_EXPRESSION <> ''",
+            (&db, &table),
+        )
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .collect();
+
     let mut columns = Vec::new();
     for row in rows {
+        let field = row.get::<Option<String>, _>("Field").flatten().unwrap_or_default();
+        let extra = row.get::<Option<String>, _>("Extra").flatten().unwrap_or_default();
+        let generation_expression = generated.get(&field).cloned();
+        // MariaDB calls stored columns PERSISTENT.
+        let generated_kind = generation_expression.as_ref().map(|_| {
+            let upper = extra.to_uppercase();
+            if upper.contains("STORED") || upper.contains("PERSISTENT") { "STORED" } else { "VIRTUAL" }.to_string()
+        });
         columns.push(ColumnInfo {
-            field: row.get::<Option<String>, _>("Field").flatten().unwrap_or_default(),
+            field,
             data_type: row.get::<Option<String>, _>("Type").flatten().unwrap_or_default(),
             collation: row.get::<Option<String>, _>("Collation").flatten(), 
             null: row.get::<Option<String>, _>("Null").flatten().unwrap_or_default(),
             key: row.get::<Option<String>, _>("Key").flatten().unwrap_or_default(),
             default: row.get::<Option<String>, _>("Default").flatten(),
-            extra: row.get::<Option<String>, _>("Extra").flatten().unwrap_or_default(),
+            extra,
+            comment: row.get::<Option<String>, _>("Comment").flatten().unwrap_or_default(),
+            generation_expression,
+            generated_kind,
         });
     }
     Ok(columns)
@@ -547,3 +949,220 @@ pub async fn table_maintenance(db: String, table: String, op: String, state: Sta
     Ok(rows)
 }
 
+
+#[derive(Deserialize, Clone)]
+pub struct IndexDefinition {
+    pub name: Option<String>,
+    pub kind: Option<String>, // "INDEX" (default), "UNIQUE", "FULLTEXT" or "SPATIAL"
+    pub columns: Vec<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct ForeignKeyDefinition {
+    pub name: Option<String>,
+    pub columns: Vec<String>,
+    pub ref_db: Option<String>,
+    pub ref_table: String,
+    pub ref_columns: Vec<String>,
+    pub on_delete: Option<String>,
+    pub on_update: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct CheckDefinition {
+    pub name: Option<String>,
+    pub expression: String,
+}
+
+#[derive(Deserialize)]
+pub struct TableDefinition {
+    pub name: String,
+    pub columns: Vec<ColumnDefinition>,
+    #[serde(default)]
+    pub primary_key: Vec<String>,
+    #[serde(default)]
+    pub indexes: Vec<IndexDefinition>,
+    #[serde(default)]
+    pub foreign_keys: Vec<ForeignKeyDefinition>,
+    #[serde(default)]
+    pub checks: Vec<CheckDefinition>,
+    pub engine: Option<String>,
+    pub charset: Option<String>,
+    pub collation: Option<String>,
+    pub row_format: Option<String>,
+    pub comment: Option<String>,
+    pub auto_increment: Option<u64>,
+    #[serde(default)]
+    pub if_not_exists: bool,
+}
+
+fn key_columns_sql(what: &str, columns: &[String], known: &[String]) -> Result<String, String> {
+    if columns.is_empty() {
+        return Err(format!("{} has no columns", what));
+    }
+    for col in columns {
+        if !known.iter().any(|k| k.eq_ignore_ascii_case(col)) {
+            return Err(format!("{} refers to unknown column `{}`", what, col));
+        }
+    }
+    Ok(columns.iter().map(|c| quote_identifier(c)).collect::<Vec<_>>().join(", "))
+}
+
+fn constraint_name_sql(name: &Option<String>) -> String {
+    match name.as_deref().filter(|n| !n.is_empty()) {
+        Some(n) => format!("CONSTRAINT {} ", quote_identifier(n)),
+        None => String::new(),
+    }
+}
+
+/// Validates a table definition and renders its `CREATE TABLE` statement.
+pub fn create_table_sql(db: &str, def: &TableDefinition, mode: SqlMode) -> Result<String, String> {
+    if def.name.trim().is_empty() {
+        return Err("Table name is required".to_string());
+    }
+    if def.columns.is_empty() {
+        return Err("A table needs at least one column".to_string());
+    }
+
+    let names: Vec<String> = def.columns.iter().map(|c| c.name.clone()).collect();
+    for (i, name) in names.iter().enumerate() {
+        if names[..i].iter().any(|n| n.eq_ignore_ascii_case(name)) {
+            return Err(format!("Duplicate column `{}`", name));
+        }
+    }
+
+    let mut lines = Vec::new();
+    for col in &def.columns {
+        // Primary key columns are always NOT NULL; an explicit NULL would fail with error 1171.
+        let in_primary_key = def.primary_key.iter().any(|k| k.eq_ignore_ascii_case(&col.name));
+        if in_primary_key && col.default_kind.as_deref() == Some("null") {
+            return Err(format!("Primary key column `{}` cannot default to NULL", col.name));
+        }
+        // Positions only make sense in ALTER TABLE.
+        let col = ColumnDefinition { first: false, after: None, nullable: col.nullable && !in_primary_key, ..col.clone() };
+        lines.push(column_definition_sql(&col, mode)?);
+    }
+
+    if !def.primary_key.is_empty() {
+        lines.push(format!("PRIMARY KEY ({})", key_columns_sql("Primary key", &def.primary_key, &names)?));
+    }
+    for (i, index) in def.indexes.iter().enumerate() {
+        let kind = index.kind.as_deref().unwrap_or("INDEX").to_uppercase();
+        let prefix = match kind.as_str() {
+            "INDEX" | "KEY" | "" => "INDEX",
+            "UNIQUE" => "UNIQUE INDEX",
+            "FULLTEXT" => "FULLTEXT INDEX",
+            "SPATIAL" => "SPATIAL INDEX",
+            other => return Err(format!("Index {}: invalid type {}", i + 1, other)),
+        };
+        let name = match index.name.as_deref().filter(|n| !n.is_empty()) {
+            Some(n) => format!("{} ", quote_identifier(n)),
+            None => String::new(),
+        };
+        lines.push(format!("{} {}({})", prefix, name, key_columns_sql(&format!("Index {}", i + 1), &index.columns, &names)?));
+    }
+
+    // Auto-increment columns must lead some key.
+    let auto: Vec<&ColumnDefinition> = def.columns.iter().filter(|c| c.auto_increment).collect();
+    if auto.len() > 1 {
+        return Err("Only one column can be AUTO_INCREMENT".to_string());
+    }
+    if let Some(col) = auto.first() {
+        let leads = |cols: &[String]| cols.first().is_some_and(|c| c.eq_ignore_ascii_case(&col.name));
+        if !leads(&def.primary_key) && !def.indexes.iter().any(|i| leads(&i.columns)) {
+            return Err(format!("AUTO_INCREMENT column `{}` must be the first column of a key", col.name));
+        }
+    }
+
+    let actions = ["RESTRICT", "CASCADE", "SET NULL", "NO ACTION", "SET DEFAULT"];
+    for (i, fk) in def.foreign_keys.iter().enumerate() {
+        let what = format!("Foreign key {}", i + 1);
+        let cols = key_columns_sql(&what, &fk.columns, &names)?;
+        if fk.ref_columns.len() != fk.columns.len() {
+            return Err(format!("{}: {} columns reference {} columns", what, fk.columns.len(), fk.ref_columns.len()));
+        }
+        let ref_cols = fk.ref_columns.iter().map(|c| quote_identifier(c)).collect::<Vec<_>>().join(", ");
+        let ref_table = match fk.ref_db.as_deref().filter(|d| !d.is_empty()) {
+            Some(ref_db) => format!("{}.{}", quote_identifier(ref_db), quote_identifier(&fk.ref_table)),
+            None => quote_identifier(&fk.ref_table),
+        };
+        let mut line = format!("{}FOREIGN KEY ({}) REFERENCES {} ({})", constraint_name_sql(&fk.name), cols, ref_table, ref_cols);
+        for (clause, action) in [("ON DELETE", &fk.on_delete), ("ON UPDATE", &fk.on_update)] {
+            if let Some(action) = action.as_deref().filter(|a| !a.is_empty()) {
+                let action = action.to_uppercase();
+                if !actions.contains(&action.as_str()) {
+                    return Err(format!("{}: invalid {} action {}", what, clause, action));
+                }
+                line.push_str(&format!(" {} {}", clause, action));
+            }
+        }
+        lines.push(line);
+    }
+
+    for (i, check) in def.checks.iter().enumerate() {
+        if check.expression.trim().is_empty() {
+            return Err(format!("Check {} has no expression", i + 1));
+        }
+        lines.push(format!("{}CHECK ({})", constraint_name_sql(&check.name), check.expression.trim()));
+    }
+
+    let mut options = Vec::new();
+    if let Some(engine) = def.engine.as_deref().filter(|e| !e.is_empty()) {
+        check_word("engine", engine)?;
+        options.push(format!("ENGINE={}", engine));
+    }
+    if let Some(charset) = def.charset.as_deref().filter(|c| !c.is_empty()) {
+        check_word("character set", charset)?;
+        options.push(format!("DEFAULT CHARSET={}", charset));
+    }
+    if let Some(collation) = def.collation.as_deref().filter(|c| !c.is_empty()) {
+        check_word("collation", collation)?;
+        options.push(format!("COLLATE={}", collation));
+    }
+    if let Some(row_format) = def.row_format.as_deref().filter(|r| !r.is_empty()) {
+        let row_format = row_format.to_uppercase();
+        if !["DEFAULT", "DYNAMIC", "FIXED", "COMPRESSED", "REDUNDANT", "COMPACT", "PAGE"].contains(&row_format.as_str()) {
+            return Err(format!("Invalid row format: {}", row_format));
+        }
+        options.push(format!("ROW_FORMAT={}", row_format));
+    }
+    if let Some(start) = def.auto_increment {
+        options.push(format!("AUTO_INCREMENT={}", start));
+    }
+    if let Some(comment) = def.comment.as_deref().filter(|c| !c.is_empty()) {
+        options.push(format!("COMMENT={}", mode.quote(comment)));
+    }
+
+    let mut sql = format!(
+        "CREATE TABLE {}{}.{} (\n  {}\n)",
+        if def.if_not_exists { "IF NOT EXISTS " } else { "" },
+        quote_identifier(db),
+        quote_identifier(&def.name),
+        lines.join(",\n  ")
+    );
+    if !options.is_empty() {
+        sql.push(' ');
+        sql.push_str(&options.join(" "));
+    }
+    Ok(sql)
+}
+
+/// Returns the `CREATE TABLE` statement for a definition without executing it.
+#[tauri::command]
+pub async fn preview_create_table(db: String, definition: TableDefinition, state: State<'_, AppState>) -> Result<String, String> {
+    let pool = state.pool.lock().unwrap().as_ref().cloned();
+    create_table_sql(&db, &definition, SqlMode::of_pool(pool).await?)
+}
+
+/// Creates a table from a structured definition and returns the SQL that ran.
+#[tauri::command]
+pub async fn create_table(db: String, definition: TableDefinition, state: State<'_, AppState>) -> Result<String, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+    let sql = create_table_sql(&db, &definition, SqlMode::load(&mut conn).await?)?;
+    conn.query_drop(&sql).await.map_err(|e| e.to_string())?;
+    Ok(sql)
+}
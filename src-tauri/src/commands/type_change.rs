@@ -0,0 +1,406 @@
+use tauri::State;
+use crate::state::AppState;
+use crate::commands::columns::{alter_columns_sql, column_type_sql, ColumnChange, ColumnDefinition};
+use crate::commands::common::{mysql_to_json, quote_identifier, text_protocol_value, SqlMode};
+use crate::commands::values::{load_column_meta, ColumnMeta};
+use mysql_async::prelude::*;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct ValueChange {
+    pub from: serde_json::Value,
+    pub to: serde_json::Value,
+}
+
+#[derive(Serialize)]
+pub struct ConversionIssue {
+    pub kind: String, // truncated, rounded, rejected, zeroed, nulled or converted
+    pub count: u64,
+    pub message: String,
+    pub examples: Vec<ValueChange>,
+}
+
+#[derive(Serialize)]
+pub struct TypeChangeReport {
+    pub column: String,
+    pub current_type: String,
+    pub target_type: String,
+    pub sql_mode: String,
+    pub strict: bool,
+    pub rows_checked: u64,
+    pub sampled: bool,
+    pub issues: Vec<ConversionIssue>,
+    pub algorithm: String, // INSTANT, INPLACE or COPY (estimated)
+    pub algorithm_reason: String,
+    pub table_rows: u64,
+    pub table_bytes: u64,
+    pub alter_sql: String,
+}
+
+const CHECK_TABLE: &str = "_omnimin_type_check";
+const EXAMPLES: u32 = 5;
+const NUMBER_PATTERN: &str = "^[[:space:]]*[-+]?([0-9]+[.]?[0-9]*|[.][0-9]+)([eE][-+]?[0-9]+)?[[:space:]]*$";
+
+#[derive(PartialEq, Clone, Copy)]
+enum Target {
+    Numeric,
+    Temporal,
+    Text,
+    Binary,
+    Member, // ENUM / SET
+    Json,
+    Other,
+}
+
+fn target_of(data_type: &str) -> Target {
+    match data_type.to_uppercase().as_str() {
+        "TINYINT" | "SMALLINT" | "MEDIUMINT" | "INT" | "INTEGER" | "BIGINT" | "DECIMAL" | "NUMERIC" | "FLOAT" | "DOUBLE" | "REAL"
+        | "BIT" | "BOOLEAN" | "BOOL" | "YEAR" => Target::Numeric,
+        "DATE" | "DATETIME" | "TIMESTAMP" | "TIME" => Target::Temporal,
+        "CHAR" | "VARCHAR" | "TINYTEXT" | "TEXT" | "MEDIUMTEXT" | "LONGTEXT" => Target::Text,
+        "BINARY" | "VARBINARY" | "TINYBLOB" | "BLOB" | "MEDIUMBLOB" | "LONGBLOB" => Target::Binary,
+        "ENUM" | "SET" => Target::Member,
+        "JSON" => Target::Json,
+        _ => Target::Other,
+    }
+}
+
+fn is_string_source(col: &ColumnMeta) -> bool {
+    matches!(
+        col.data_type.as_str(),
+        "char" | "varchar" | "tinytext" | "text" | "mediumtext" | "longtext" | "enum" | "set" | "json"
+            | "binary" | "varbinary" | "tinyblob" | "blob" | "mediumblob" | "longblob"
+    )
+}
+
+/// SQL classifying each `src` -> `dst` pair of the check table; NULL when the value survives unchanged.
+fn kind_expr(target: Target, target_type: &str, string_source: bool) -> String {
+    let changed = match target {
+        Target::Numeric => {
+            let non_numeric = if string_source {
+                format!("WHEN CAST(src AS CHAR) NOT REGEXP '{}' THEN IF(dst + 0 = 0, 'zeroed', 'truncated') ", NUMBER_PATTERN)
+            } else {
+                String::new()
+            };
+            format!("{}WHEN src + 0 = dst + 0 THEN NULL WHEN ABS(src + 0 - (dst + 0)) < 1 THEN 'rounded' ELSE 'truncated'", non_numeric)
+        }
+        Target::Temporal => "WHEN CAST(dst AS CHAR) LIKE '0000-00-00%' THEN IF(CAST(src AS CHAR) LIKE '0000-00-00%', NULL, 'zeroed') \
+             WHEN CAST(src AS CHAR) = CAST(dst AS CHAR) OR src = dst THEN NULL \
+             WHEN LEFT(CAST(src AS CHAR), 19) = LEFT(CAST(dst AS CHAR), 19) THEN 'rounded' ELSE 'truncated'"
+            .to_string(),
+        Target::Text => "WHEN CONVERT(src USING utf8mb4) COLLATE utf8mb4_bin = CONVERT(dst USING utf8mb4) COLLATE utf8mb4_bin THEN NULL \
+             WHEN CHAR_LENGTH(dst) < CHAR_LENGTH(src) THEN 'truncated' ELSE 'converted'"
+            .to_string(),
+        Target::Binary => {
+            // BINARY(n) pads with zero bytes.
+            let stored = if target_type.starts_with("BINARY") { "TRIM(TRAILING 0x00 FROM dst)" } else { "dst" };
+            format!("WHEN BINARY src = {0} THEN NULL WHEN LENGTH({0}) < LENGTH(BINARY src) THEN 'truncated' ELSE 'converted'", stored)
+        }
+        Target::Member => "WHEN CAST(src AS CHAR) = CAST(dst AS CHAR) THEN NULL WHEN CAST(dst AS CHAR) = '' THEN 'zeroed' ELSE 'converted'".to_string(),
+        Target::Json => "WHEN NOT JSON_VALID(src) THEN 'invalid' ELSE NULL".to_string(),
+        Target::Other => "WHEN CAST(src AS CHAR) = CAST(dst AS CHAR) THEN NULL ELSE 'converted'".to_string(),
+    };
+    format!("CASE WHEN src IS NULL THEN NULL WHEN dst IS NULL THEN 'nulled' {} END", changed)
+}
+
+/// Final kind and explanation, taking strict mode into account.
+fn describe(raw: &str, target: Target, strict: bool) -> (String, String) {
+    let message = match raw {
+        "nulled" => "cannot be represented and become NULL",
+        "zeroed" => "are not valid for the target type and become zero, an empty value or a zero date",
+        "rounded" => "lose fractional digits or fractional seconds",
+        "truncated" if target == Target::Numeric => "are out of range and are clamped to the type's limit",
+        "truncated" if target == Target::Temporal => "lose their date or time part",
+        "truncated" => "are longer than the target type and are cut off",
+        "converted" => "are stored differently, e.g. characters missing from the target character set",
+        "invalid" => "are not valid JSON",
+        _ => "change",
+    };
+    // Rounding and dropping a time part are notes; JSON errors happen in any mode.
+    let rejected = raw == "invalid" || (strict && raw != "rounded" && !(raw == "truncated" && target == Target::Temporal));
+    if rejected {
+        ("rejected".to_string(), format!("Values that {}; the ALTER fails under the current sql_mode", message))
+    } else {
+        (raw.to_string(), format!("Values that {}", message))
+    }
+}
+
+/// Drops display widths so `int(11)` and `INT` compare equal.
+fn normalize_type(column_type: &str) -> String {
+    let lower = column_type.to_lowercase();
+    for int in ["tinyint", "smallint", "mediumint", "bigint", "int"] {
+        if let Some(rest) = lower.strip_prefix(int).and_then(|r| r.strip_prefix('(')) {
+            if let Some(close) = rest.find(')') {
+                return format!("{}{}", int, &rest[close + 1..]);
+            }
+        }
+    }
+    lower
+}
+
+fn members_storage(kind: &str, count: usize) -> usize {
+    if kind == "ENUM" {
+        if count <= 255 { 1 } else { 2 }
+    } else {
+        match count.div_ceil(8) {
+            n @ 1..=4 => n,
+            _ => 8,
+        }
+    }
+}
+
+struct CurrentColumn {
+    meta: ColumnMeta,
+    charset: Option<String>,
+    collation: Option<String>,
+}
+
+/// Rough InnoDB online DDL estimate for changing `current` to `target`.
+fn estimate_algorithm(
+    engine: &str,
+    current: &CurrentColumn,
+    target: &ColumnDefinition,
+    target_type: &str,
+    target_charset: Option<&str>,
+    target_collation: Option<&str>,
+    maxlen: (u64, u64),
+) -> (String, String) {
+    let same_type = normalize_type(&current.meta.column_type) == normalize_type(target_type);
+    let same_charset = target_charset.is_none() || target_charset == current.charset.as_deref();
+    let same_collation = target_collation.is_none() || target_collation == current.collation.as_deref();
+    let same_null = target.nullable == current.meta.nullable;
+    let result = |algorithm: &str, reason: &str| (algorithm.to_string(), reason.to_string());
+
+    if same_type && same_charset && same_collation && same_null {
+        return result("INSTANT", "Only metadata such as the default or comment changes");
+    }
+    if !engine.eq_ignore_ascii_case("InnoDB") {
+        return result("COPY", &format!("{} tables are copied for column type changes", engine));
+    }
+    if same_type && same_charset {
+        return if same_null {
+            result("INPLACE", "Collation change; the table is rebuilt in place unless the column is indexed")
+        } else {
+            result("INPLACE", "Nullability change; the table is rebuilt in place and stays writable")
+        };
+    }
+    if !same_charset {
+        return result("COPY", "Character set changes convert every row");
+    }
+
+    let base = target.data_type.trim().to_uppercase();
+    let current_base = current.meta.data_type.to_uppercase();
+    if same_null && base == current_base && (base == "VARCHAR" || base == "VARBINARY") {
+        let old_len = current.meta.char_max_length.unwrap_or(0);
+        let new_len: u64 = target.length.as_deref().and_then(|l| l.trim().parse().ok()).unwrap_or(0);
+        let (old_bytes, new_bytes) = (old_len * maxlen.0, new_len * maxlen.1);
+        if new_len >= old_len && (old_bytes <= 255) == (new_bytes <= 255) {
+            return result("INPLACE", "Extending a VARCHAR within the same length-prefix size only changes metadata");
+        }
+        if new_len >= old_len {
+            return result("COPY", "The VARCHAR crosses the 255-byte boundary, which needs a 2-byte length prefix");
+        }
+    }
+    if same_null && base == current_base && (base == "ENUM" || base == "SET") {
+        let old = current.meta.members();
+        let appended = target.values.len() >= old.len() && target.values[..old.len()] == old[..];
+        if appended && members_storage(&base, old.len()) == members_storage(&base, target.values.len()) {
+            return result("INSTANT", "Members are only appended and the storage size is unchanged");
+        }
+    }
+    result("COPY", "The data type changes, so every row is rewritten into a table copy")
+}
+
+/// Checks what changing a column to `target` would do to the existing data: the column's values
+/// are copied into a temporary table with the target type under the session's `sql_mode`, and
+/// every value that comes out different is classified. Also estimates the ALTER algorithm and
+/// returns the statement to run (through `alter_columns`) once the report looks acceptable.
+#[tauri::command]
+pub async fn analyze_type_change(
+    db: String,
+    table: String,
+    column: String,
+    target: ColumnDefinition,
+    sample_rows: Option<u64>,
+    state: State<'_, AppState>,
+) -> Result<TypeChangeReport, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let mode = SqlMode::load(&mut conn).await?;
+    let target_type = column_type_sql(&target, mode)?;
+    let alter_sql = alter_columns_sql(
+        &db,
+        &table,
+        &[ColumnChange { action: "modify".to_string(), column: Some(column.clone()), definition: Some(target.clone()), new_name: None }],
+        mode,
+    )?;
+
+    let meta = load_column_meta(&mut conn, &db, &table)
+        .await?
+        .into_iter()
+        .find(|c| c.name == column)
+        .ok_or(format!("Column `{}` not found", column))?;
+    let names: Option<(Option<String>, Option<String>)> = conn
+        .exec_first(
+            "SELECT CHARACTER_SET_NAME, COLLATION_NAME FROM information_schema.COLUMNS WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ? AND COLUMN_NAME = ?",
+            (&db, &table, &column),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    let (charset, collation) = names.unwrap_or_default();
+    let current = CurrentColumn { meta, charset, collation };
+
+    #[allow(clippy::type_complexity)]
+    let info: Option<(Option<String>, Option<String>, Option<u64>, Option<u64>, Option<u64>)> = conn
+        .exec_first(
+            "SELECT ENGINE, TABLE_COLLATION, TABLE_ROWS, DATA_LENGTH, INDEX_LENGTH FROM information_schema.TABLES WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ?",
+            (&db, &table),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    let (engine, table_collation, table_rows, data_length, index_length) = info.ok_or(format!("Table `{}`.`{}` not found", db, table))?;
+    let sql_mode: String = conn.query_first("SELECT @@SESSION.sql_mode").await.map_err(|e| e.to_string())?.unwrap_or_default();
+    let strict = sql_mode.contains("STRICT_TRANS_TABLES") || sql_mode.contains("STRICT_ALL_TABLES");
+
+    // MODIFY without a character set uses the table default, not the column's current one.
+    let target_kind = target_of(&target.data_type);
+    let is_text = matches!(target_kind, Target::Text | Target::Member);
+    let target_collation = target.collation.clone().filter(|c| !c.is_empty()).or_else(|| {
+        (is_text && target.charset.as_deref().map_or(true, str::is_empty)).then(|| table_collation.clone()).flatten()
+    });
+    let target_charset = match target.charset.clone().filter(|c| !c.is_empty()) {
+        Some(cs) => Some(cs),
+        None if is_text => match &target_collation {
+            Some(coll) => conn
+                .exec_first("SELECT CHARACTER_SET_NAME FROM information_schema.COLLATIONS WHERE COLLATION_NAME = ?", (coll,))
+                .await
+                .map_err(|e| e.to_string())?,
+            None => None,
+        },
+        None => None,
+    };
+    let mut maxlen = (1, 1);
+    for (slot, cs) in [(&mut maxlen.0, current.charset.clone()), (&mut maxlen.1, target_charset.clone())] {
+        if let Some(cs) = cs {
+            let len: Option<u64> = conn
+                .exec_first("SELECT MAXLEN FROM information_schema.CHARACTER_SETS WHERE CHARACTER_SET_NAME = ?", (&cs,))
+                .await
+                .map_err(|e| e.to_string())?;
+            *slot = len.unwrap_or(1);
+        }
+    }
+    let (algorithm, algorithm_reason) = estimate_algorithm(
+        engine.as_deref().unwrap_or("InnoDB"),
+        &current,
+        &target,
+        &target_type,
+        if is_text { target_charset.as_deref() } else { None },
+        if is_text { target_collation.as_deref() } else { None },
+        maxlen,
+    );
+
+    // Copy the values through a temporary column of the target type.
+    let column_sql = |ty: &str, charset: Option<&String>, collation: Option<&String>| {
+        let mut sql = ty.to_string();
+        if let Some(cs) = charset {
+            sql.push_str(&format!(" CHARACTER SET {}", cs));
+        }
+        if let Some(coll) = collation {
+            sql.push_str(&format!(" COLLATE {}", coll));
+        }
+        sql
+    };
+    let check_table = format!("{}.{}", quote_identifier(&db), quote_identifier(CHECK_TABLE));
+    // JSON validity is checked directly; storing invalid JSON fails even with IGNORE.
+    let dst_type = if target_kind == Target::Json { "LONGTEXT".to_string() } else { column_sql(&target_type, target_charset.as_ref(), target_collation.as_ref()) };
+    let src_type = column_sql(&current.meta.column_type, current.charset.as_ref(), current.collation.as_ref());
+    conn.query_drop(format!("DROP TEMPORARY TABLE IF EXISTS {}", check_table)).await.map_err(|e| e.to_string())?;
+    conn.query_drop(format!("CREATE TEMPORARY TABLE {} (src {} NULL, dst {} NULL)", check_table, src_type, dst_type))
+        .await
+        .map_err(|e| format!("Could not create a column of the target type: {}", e))?;
+
+    let analysis = async {
+        let limit = sample_rows.map(|n| format!(" LIMIT {}", n)).unwrap_or_default();
+        let col = quote_identifier(&column);
+        conn.query_drop(format!(
+            "INSERT IGNORE INTO {0} (src, dst) SELECT {1}, {1} FROM {2}.{3}{4}",
+            check_table, col, quote_identifier(&db), quote_identifier(&table), limit
+        ))
+        .await
+        .map_err(|e| e.to_string())?;
+        let rows_checked = conn.affected_rows();
+
+        let kind = kind_expr(target_kind, &target_type, is_string_source(&current.meta));
+        let counts: Vec<(String, u64)> = conn
+            .query(format!("SELECT k, COUNT(*) FROM (SELECT {} AS k FROM {}) AS c WHERE k IS NOT NULL GROUP BY k ORDER BY k", kind, check_table))
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut issues = Vec::new();
+        let not_null_target = !target.nullable && target.generated_expression.as_deref().map_or(true, |g| g.trim().is_empty());
+        if not_null_target && current.meta.nullable {
+            let nulls: Option<u64> = conn
+                .query_first(format!("SELECT COUNT(*) FROM {} WHERE src IS NULL", check_table))
+                .await
+                .map_err(|e| e.to_string())?;
+            if let Some(n) = nulls.filter(|n| *n > 0) {
+                let (kind, message) = if strict {
+                    ("rejected", "NULL values in a NOT NULL column; the ALTER fails under the current sql_mode")
+                } else {
+                    ("zeroed", "NULL values become the type's implicit default (0, '' or a zero date)")
+                };
+                issues.push(ConversionIssue { kind: kind.to_string(), count: n, message: message.to_string(), examples: Vec::new() });
+            }
+        }
+        for (raw, count) in counts {
+            let examples: Vec<mysql_async::Row> = conn
+                .exec(format!("SELECT src, dst FROM {} WHERE ({}) = ? LIMIT {}", check_table, kind, EXAMPLES), (&raw,))
+                .await
+                .map_err(|e| e.to_string())?;
+            let (kind, message) = describe(&raw, target_kind, strict);
+            issues.push(ConversionIssue {
+                kind,
+                count,
+                message,
+                examples: examples
+                    .into_iter()
+                    .map(|row| {
+                        let value = |i: usize| {
+                            let val = row.get(i).unwrap_or(mysql_async::Value::NULL);
+                            mysql_to_json(text_protocol_value(val, &row.columns_ref()[i]))
+                        };
+                        ValueChange {
+                            from: value(0),
+                            to: if raw == "invalid" { serde_json::Value::Null } else { value(1) },
+                        }
+                    })
+                    .collect(),
+            });
+        }
+        Ok::<_, String>((rows_checked, issues))
+    }
+    .await;
+    // Temporary tables live as long as the pooled connection, so always clean up.
+    let _ = conn.query_drop(format!("DROP TEMPORARY TABLE IF EXISTS {}", check_table)).await;
+    let (rows_checked, issues) = analysis?;
+
+    Ok(TypeChangeReport {
+        column,
+        current_type: current.meta.column_type.clone(),
+        target_type,
+        sql_mode,
+        strict,
+        rows_checked,
+        sampled: sample_rows.is_some(),
+        issues,
+        algorithm,
+        algorithm_reason,
+        table_rows: table_rows.unwrap_or(0),
+        table_bytes: data_length.unwrap_or(0) + index_length.unwrap_or(0),
+        alter_sql,
+    })
+}
@@ -0,0 +1,431 @@
+use tauri::State;
+use crate::state::AppState;
+use mysql_async::prelude::*;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Column definition used to validate and convert edited values.
+#[derive(Clone, Debug)]
+pub struct ColumnMeta {
+    pub name: String,
+    pub data_type: String,   // lower-case base type, e.g. "varchar"
+    pub column_type: String, // full type, e.g. "int(10) unsigned" or "enum('a','b')"
+    pub nullable: bool,
+    pub char_max_length: Option<u64>,
+    pub octet_length: Option<u64>,
+    pub numeric_precision: Option<u64>,
+    pub numeric_scale: Option<u64>,
+    pub extra: String,
+}
+
+impl ColumnMeta {
+    pub fn is_auto_increment(&self) -> bool {
+        self.extra.to_lowercase().contains("auto_increment")
+    }
+
+    /// Virtual or stored generated column. MySQL 8 also reports `DEFAULT_GENERATED` for
+    /// expression defaults, which are ordinary writable columns.
+    pub fn is_generated(&self) -> bool {
+        let extra = self.extra.to_uppercase();
+        extra.contains("VIRTUAL GENERATED") || extra.contains("STORED GENERATED") || extra.contains("PERSISTENT")
+    }
+
+    pub fn is_unsigned(&self) -> bool {
+        self.column_type.to_lowercase().contains("unsigned")
+    }
+
+    /// Members of an ENUM or SET column, unquoted.
+    pub fn members(&self) -> Vec<String> {
+        parse_members(&self.column_type)
+    }
+}
+
+/// Members listed in an `enum(...)` / `set(...)` column type, unquoted.
+pub fn parse_members(column_type: &str) -> Vec<String> {
+    let ct = column_type;
+    let (Some(open), Some(close)) = (ct.find('('), ct.rfind(')')) else { return Vec::new() };
+    let inner = &ct[open + 1..close];
+    let mut members = Vec::new();
+    let mut current = String::new();
+    let mut in_quote = false;
+    let mut chars = inner.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' if in_quote && chars.peek() == Some(&'\'') => {
+                current.push('\'');
+                chars.next();
+            }
+            '\'' => {
+                if in_quote {
+                    members.push(std::mem::take(&mut current));
+                }
+                in_quote = !in_quote;
+            }
+            '\\' if in_quote => {
+                if let Some(n) = chars.next() {
+                    current.push(n);
+                }
+            }
+            _ if in_quote => current.push(c),
+            _ => {}
+        }
+    }
+    members
+}
+
+pub async fn load_column_meta(conn: &mut mysql_async::Conn, db: &str, table: &str) -> Result<Vec<ColumnMeta>, String> {
+    #[allow(clippy::type_complexity)]
+    let rows: Vec<(String, String, String, String, Option<u64>, Option<u64>, Option<u64>, Option<u64>, String)> = conn
+        .exec(
+            "SELECT COLUMN_NAME, DATA_TYPE, COLUMN_TYPE, IS_NULLABLE, CHARACTER_MAXIMUM_LENGTH, CHARACTER_OCTET_LENGTH,
+                    NUMERIC_PRECISION, NUMERIC_SCALE, EXTRA
+             FROM information_schema.COLUMNS WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ? ORDER BY ORDINAL_POSITION",
+            (db, table),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    if rows.is_empty() {
+        return Err(format!("Table `{}`.`{}` not found", db, table));
+    }
+    Ok(rows
+        .into_iter()
+        .map(|(name, data_type, column_type, nullable, char_max, octets, precision, scale, extra)| ColumnMeta {
+            name,
+            data_type: data_type.to_lowercase(),
+            column_type,
+            nullable: nullable == "YES",
+            char_max_length: char_max,
+            octet_length: octets,
+            numeric_precision: precision,
+            numeric_scale: scale,
+            extra,
+        })
+        .collect())
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct CellError {
+    pub operation: String, // "insert", "update" or "edit"
+    pub row: usize,        // index within the submitted rows
+    pub column: String,
+    pub message: String,
+}
+
+pub fn format_cell_errors(errors: &[CellError]) -> String {
+    errors
+        .iter()
+        .map(|e| format!("{} {}, `{}`: {}", e.operation, e.row + 1, e.column, e.message))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    let hex = hex.trim_start_matches("0x");
+    if hex.len() % 2 != 0 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// Loose conversion for values that only need to be compared, e.g. row identities.
+pub fn json_param(val: &serde_json::Value) -> mysql_async::Value {
+    match val {
+        serde_json::Value::Null => mysql_async::Value::NULL,
+        serde_json::Value::Bool(b) => mysql_async::Value::Int(*b as i64),
+        serde_json::Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                mysql_async::Value::Int(i)
+            } else if let Some(u) = n.as_u64() {
+                mysql_async::Value::UInt(u)
+            } else {
+                mysql_async::Value::Double(n.as_f64().unwrap_or_default())
+            }
+        }
+        serde_json::Value::String(s) => mysql_async::Value::Bytes(s.as_bytes().to_vec()),
+        other => match other.get("$hex").and_then(|h| h.as_str()).and_then(decode_hex) {
+            Some(bytes) => mysql_async::Value::Bytes(bytes),
+            None => mysql_async::Value::Bytes(other.to_string().into_bytes()),
+        },
+    }
+}
+
+fn as_text(val: &serde_json::Value) -> String {
+    match val {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Bool(b) => if *b { "1".to_string() } else { "0".to_string() },
+        other => other.to_string(),
+    }
+}
+
+fn int_range(data_type: &str, unsigned: bool) -> (i128, i128) {
+    let bits = match data_type {
+        "tinyint" => 8,
+        "smallint" => 16,
+        "mediumint" => 24,
+        "int" | "integer" => 32,
+        _ => 64,
+    };
+    if unsigned {
+        (0, (1i128 << bits) - 1)
+    } else {
+        (-(1i128 << (bits - 1)), (1i128 << (bits - 1)) - 1)
+    }
+}
+
+fn parse_datetime(text: &str) -> Option<chrono::NaiveDateTime> {
+    let text = text.trim();
+    for fmt in ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M"] {
+        if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(text, fmt) {
+            return Some(dt);
+        }
+    }
+    chrono::NaiveDate::parse_from_str(text, "%Y-%m-%d").ok().and_then(|d| d.and_hms_opt(0, 0, 0))
+}
+
+fn parse_time(text: &str) -> Option<mysql_async::Value> {
+    let text = text.trim();
+    let (neg, body) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let (clock, frac) = match body.split_once('.') {
+        Some((c, f)) => (c, f),
+        None => (body, ""),
+    };
+    let parts: Vec<&str> = clock.split(':').collect();
+    if parts.len() < 2 || parts.len() > 3 || parts.iter().any(|p| p.is_empty() || !p.chars().all(|c| c.is_ascii_digit())) {
+        return None;
+    }
+    let hours: u32 = parts[0].parse().ok()?;
+    let minutes: u8 = parts[1].parse().ok()?;
+    let seconds: u8 = parts.get(2).map(|s| s.parse().ok()).unwrap_or(Some(0))?;
+    if hours > 838 || minutes > 59 || seconds > 59 || frac.len() > 6 || !frac.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let micros: u32 = if frac.is_empty() { 0 } else { format!("{:0<6}", frac).parse().ok()? };
+    Some(mysql_async::Value::Time(neg, hours / 24, (hours % 24) as u8, minutes, seconds, micros))
+}
+
+// TIMESTAMP bounds in UTC; the session time zone can shift them by a few hours.
+fn timestamp_min() -> chrono::NaiveDateTime {
+    chrono::NaiveDate::from_ymd_opt(1970, 1, 1).and_then(|d| d.and_hms_opt(0, 0, 1)).unwrap_or_default()
+}
+
+fn timestamp_max() -> chrono::NaiveDateTime {
+    chrono::NaiveDate::from_ymd_opt(2038, 1, 19).and_then(|d| d.and_hms_micro_opt(3, 14, 7, 999_999)).unwrap_or_default()
+}
+
+/// Validates `val` against the column definition and converts it into a bound parameter.
+pub fn to_param(col: &ColumnMeta, val: &serde_json::Value) -> Result<mysql_async::Value, String> {
+    if col.is_generated() {
+        return Err("is a generated column and cannot be written".to_string());
+    }
+    if val.is_null() {
+        if !col.nullable && !col.is_auto_increment() {
+            return Err("cannot be NULL".to_string());
+        }
+        return Ok(mysql_async::Value::NULL);
+    }
+
+    let text = as_text(val);
+    let unsigned = col.is_unsigned();
+    match col.data_type.as_str() {
+        "tinyint" | "smallint" | "mediumint" | "int" | "integer" | "bigint" => {
+            let n: i128 = match val {
+                serde_json::Value::Number(n) if n.is_f64() => {
+                    let f = n.as_f64().unwrap_or_default();
+                    if f.fract() != 0.0 {
+                        return Err(format!("expects an integer, got {}", f));
+                    }
+                    f as i128
+                }
+                _ => text.trim().parse().map_err(|_| format!("expects an integer, got `{}`", text))?,
+            };
+            let (min, max) = int_range(&col.data_type, unsigned);
+            if n < min || n > max {
+                return Err(format!("{} is out of range for {} ({}..{})", n, col.column_type, min, max));
+            }
+            Ok(if n < 0 { mysql_async::Value::Int(n as i64) } else { mysql_async::Value::UInt(n as u64) })
+        }
+        "bit" => {
+            let n: u64 = text.trim().parse().map_err(|_| format!("expects a number for {}, got `{}`", col.column_type, text))?;
+            let bits = col.numeric_precision.unwrap_or(1).min(64);
+            if bits < 64 && n >= (1u64 << bits) {
+                return Err(format!("{} does not fit in {}", n, col.column_type));
+            }
+            Ok(mysql_async::Value::UInt(n))
+        }
+        "decimal" | "numeric" => {
+            let t = text.trim();
+            let unsigned_part = t.strip_prefix(['+', '-']).unwrap_or(t);
+            let (int_part, frac_part) = unsigned_part.split_once('.').unwrap_or((unsigned_part, ""));
+            let valid = !(int_part.is_empty() && frac_part.is_empty())
+                && int_part.chars().all(|c| c.is_ascii_digit())
+                && frac_part.chars().all(|c| c.is_ascii_digit());
+            if !valid {
+                return Err(format!("expects a decimal number, got `{}`", text));
+            }
+            if unsigned && t.starts_with('-') && unsigned_part.chars().any(|c| c != '0' && c != '.') {
+                return Err("is UNSIGNED and cannot be negative".to_string());
+            }
+            let precision = col.numeric_precision.unwrap_or(65);
+            let scale = col.numeric_scale.unwrap_or(0);
+            let int_digits = int_part.trim_start_matches('0').len() as u64;
+            if int_digits > precision.saturating_sub(scale) {
+                return Err(format!("{} is out of range for {}", t, col.column_type));
+            }
+            if frac_part.len() as u64 > scale {
+                return Err(format!("{} has more than {} decimal place(s)", t, scale));
+            }
+            Ok(mysql_async::Value::Bytes(t.as_bytes().to_vec()))
+        }
+        "float" | "double" | "real" => {
+            let f: f64 = text.trim().parse().map_err(|_| format!("expects a number, got `{}`", text))?;
+            if !f.is_finite() {
+                return Err("must be a finite number".to_string());
+            }
+            if unsigned && f < 0.0 {
+                return Err("is UNSIGNED and cannot be negative".to_string());
+            }
+            Ok(mysql_async::Value::Double(f))
+        }
+        "enum" => {
+            let members = col.members();
+            match members.iter().find(|m| m.eq_ignore_ascii_case(&text)) {
+                Some(m) => Ok(mysql_async::Value::Bytes(m.clone().into_bytes())),
+                None => Err(format!("`{}` is not one of: {}", text, members.join(", "))),
+            }
+        }
+        "set" => {
+            let members = col.members();
+            let mut chosen = Vec::new();
+            for part in text.split(',').map(|p| p.trim()).filter(|p| !p.is_empty()) {
+                match members.iter().find(|m| m.eq_ignore_ascii_case(part)) {
+                    Some(m) => chosen.push(m.clone()),
+                    None => return Err(format!("`{}` is not one of: {}", part, members.join(", "))),
+                }
+            }
+            Ok(mysql_async::Value::Bytes(chosen.join(",").into_bytes()))
+        }
+        "date" => {
+            use chrono::Datelike;
+            let d = parse_datetime(&text).ok_or(format!("`{}` is not a valid date (YYYY-MM-DD)", text))?.date();
+            Ok(mysql_async::Value::Date(d.year() as u16, d.month() as u8, d.day() as u8, 0, 0, 0, 0))
+        }
+        "datetime" | "timestamp" => {
+            use chrono::{Datelike, Timelike};
+            let dt = parse_datetime(&text).ok_or(format!("`{}` is not a valid date/time (YYYY-MM-DD HH:MM:SS)", text))?;
+            if col.data_type == "timestamp" && !(timestamp_min()..=timestamp_max()).contains(&dt) {
+                return Err(format!("`{}` is outside the TIMESTAMP range (1970-01-01 00:00:01 to 2038-01-19 03:14:07 UTC)", text));
+            }
+            Ok(mysql_async::Value::Date(
+                dt.year() as u16, dt.month() as u8, dt.day() as u8,
+                dt.hour() as u8, dt.minute() as u8, dt.second() as u8,
+                dt.nanosecond() / 1000,
+            ))
+        }
+        "time" => parse_time(&text).ok_or(format!("`{}` is not a valid time ([-]HHH:MM:SS)", text)),
+        "year" => {
+            let y: u16 = text.trim().parse().map_err(|_| format!("expects a year, got `{}`", text))?;
+            if y != 0 && !(1901..=2155).contains(&y) {
+                return Err(format!("{} is outside the YEAR range (1901-2155)", y));
+            }
+            Ok(mysql_async::Value::UInt(y as u64))
+        }
+        "json" => {
+            let json = match val {
+                serde_json::Value::String(s) => {
+                    serde_json::from_str::<serde_json::Value>(s).map_err(|e| format!("is not valid JSON: {}", e))?;
+                    s.clone()
+                }
+                other => other.to_string(),
+            };
+            Ok(mysql_async::Value::Bytes(json.into_bytes()))
+        }
+        "binary" | "varbinary" | "tinyblob" | "blob" | "mediumblob" | "longblob" => {
+            let bytes = match val.get("$hex").and_then(|h| h.as_str()) {
+                Some(hex) => decode_hex(hex).ok_or("contains invalid hex data")?,
+                None => text.into_bytes(),
+            };
+            if let Some(max) = col.octet_length {
+                if bytes.len() as u64 > max {
+                    return Err(format!("{} bytes exceed the maximum of {}", bytes.len(), max));
+                }
+            }
+            Ok(mysql_async::Value::Bytes(bytes))
+        }
+        "char" | "varchar" => {
+            if let Some(max) = col.char_max_length {
+                let len = text.chars().count() as u64;
+                if len > max {
+                    return Err(format!("{} characters exceed the maximum of {}", len, max));
+                }
+            }
+            Ok(mysql_async::Value::Bytes(text.into_bytes()))
+        }
+        "tinytext" | "text" | "mediumtext" | "longtext" => {
+            if let Some(max) = col.octet_length {
+                if text.len() as u64 > max {
+                    return Err(format!("{} bytes exceed the maximum of {}", text.len(), max));
+                }
+            }
+            Ok(mysql_async::Value::Bytes(text.into_bytes()))
+        }
+        _ => Ok(mysql_async::Value::Bytes(text.into_bytes())),
+    }
+}
+
+/// Converts the given columns of a row, in table column order, recording any per-cell errors.
+pub fn convert_row(
+    columns: &[ColumnMeta],
+    row: &HashMap<String, serde_json::Value>,
+    operation: &str,
+    index: usize,
+    errors: &mut Vec<CellError>,
+) -> Vec<(String, mysql_async::Value)> {
+    for name in row.keys() {
+        if !columns.iter().any(|c| &c.name == name) {
+            errors.push(CellError {
+                operation: operation.to_string(),
+                row: index,
+                column: name.clone(),
+                message: "unknown column".to_string(),
+            });
+        }
+    }
+    let mut out = Vec::new();
+    for col in columns {
+        if let Some(val) = row.get(&col.name) {
+            match to_param(col, val) {
+                Ok(v) => out.push((col.name.clone(), v)),
+                Err(message) => errors.push(CellError {
+                    operation: operation.to_string(),
+                    row: index,
+                    column: col.name.clone(),
+                    message,
+                }),
+            }
+        }
+    }
+    out
+}
+
+/// Checks rows against the table definition without writing anything.
+#[tauri::command]
+pub async fn validate_rows(db: String, table: String, rows: Vec<HashMap<String, serde_json::Value>>, state: State<'_, AppState>) -> Result<Vec<CellError>, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let columns = load_column_meta(&mut conn, &db, &table).await?;
+    let mut errors = Vec::new();
+    for (i, row) in rows.iter().enumerate() {
+        convert_row(&columns, row, "edit", i, &mut errors);
+    }
+    Ok(errors)
+}
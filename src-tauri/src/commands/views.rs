@@ -0,0 +1,284 @@
+use tauri::State;
+use crate::state::AppState;
+use crate::commands::common::quote_identifier;
+use crate::commands::sql_lexer::{tokenize, TokenKind};
+use mysql_async::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize)]
+pub struct ViewInfo {
+    pub name: String,
+    pub definer: String,
+    pub security_type: String, // DEFINER or INVOKER
+    pub check_option: String,  // NONE, CASCADED or LOCAL
+    pub is_updatable: bool,
+}
+
+#[derive(Serialize)]
+pub struct ViewDefinition {
+    pub name: String,
+    pub definer: String,
+    pub security_type: String,
+    pub check_option: String,
+    pub algorithm: String, // UNDEFINED, MERGE or TEMPTABLE
+    pub is_updatable: bool,
+    pub select_sql: String,
+    pub create_sql: String,
+    pub character_set_client: String,
+    pub collation_connection: String,
+}
+
+#[derive(Deserialize)]
+pub struct ViewSpec {
+    pub name: String,
+    pub select_sql: String,
+    pub columns: Option<Vec<String>>,
+    pub algorithm: Option<String>,
+    pub definer: Option<String>, // `user`@`host` or CURRENT_USER
+    pub security_type: Option<String>,
+    pub check_option: Option<String>, // NONE, CASCADED or LOCAL
+    #[serde(default)]
+    pub or_replace: bool,
+}
+
+#[derive(Serialize)]
+pub struct ViewUpdatability {
+    pub is_updatable: bool,   // as reported by the server
+    pub reasons: Vec<String>, // constructs that prevent updates, when not updatable
+}
+
+#[tauri::command]
+pub async fn get_views(db: String, state: State<'_, AppState>) -> Result<Vec<ViewInfo>, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let rows: Vec<(String, String, String, String, String)> = conn
+        .exec(
+            "SELECT TABLE_NAME, DEFINER, SECURITY_TYPE, CHECK_OPTION, IS_UPDATABLE
+             FROM information_schema.VIEWS WHERE TABLE_SCHEMA = ? ORDER BY TABLE_NAME",
+            (&db,),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(name, definer, security_type, check_option, updatable)| ViewInfo {
+            name,
+            definer,
+            security_type,
+            check_option,
+            is_updatable: updatable == "YES",
+        })
+        .collect())
+}
+
+/// Value of `KEY=value` in the head of a `SHOW CREATE VIEW` statement.
+fn create_option(create_sql: &str, key: &str) -> Option<String> {
+    let head = &create_sql[..create_sql.find(" VIEW ").unwrap_or(create_sql.len())];
+    let start = head.find(&format!("{}=", key))? + key.len() + 1;
+    Some(head[start..].split_whitespace().next()?.to_string())
+}
+
+#[tauri::command]
+pub async fn get_view_definition(db: String, name: String, state: State<'_, AppState>) -> Result<ViewDefinition, String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+
+    let row: Option<(String, String, String, String, String)> = conn
+        .exec_first(
+            "SELECT VIEW_DEFINITION, DEFINER, SECURITY_TYPE, CHECK_OPTION, IS_UPDATABLE
+             FROM information_schema.VIEWS WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ?",
+            (&db, &name),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    let (select_sql, definer, security_type, check_option, updatable) = row.ok_or(format!("View `{}`.`{}` not found", db, name))?;
+
+    // Columns: View, Create View, character_set_client, collation_connection
+    let create: Option<(String, String, String, String)> = conn
+        .query_first(format!("SHOW CREATE VIEW {}.{}", quote_identifier(&db), quote_identifier(&name)))
+        .await
+        .map_err(|e| e.to_string())?;
+    let (_, create_sql, character_set_client, collation_connection) = create.unwrap_or_default();
+
+    Ok(ViewDefinition {
+        algorithm: create_option(&create_sql, "ALGORITHM").unwrap_or_else(|| "UNDEFINED".to_string()),
+        name,
+        definer,
+        security_type,
+        check_option,
+        is_updatable: updatable == "YES",
+        select_sql,
+        create_sql,
+        character_set_client,
+        collation_connection,
+    })
+}
+
+/// One part of an account name: bare, or wrapped in backticks, single or double quotes
+/// with the quote doubled inside.
+fn account_part(part: &str) -> Option<String> {
+    for q in ['`', '\'', '"'] {
+        if let Some(inner) = part.strip_prefix(q).and_then(|p| p.strip_suffix(q)).filter(|_| part.len() >= 2) {
+            let doubled = q.to_string().repeat(2);
+            return (!inner.replace(&doubled, "").contains(q)).then(|| inner.replace(&doubled, &q.to_string()));
+        }
+    }
+    let bare = !part.is_empty() && part.chars().all(|c| c.is_alphanumeric() || matches!(c, '_' | '$' | '.' | '-' | '%'));
+    bare.then(|| part.to_string())
+}
+
+/// `CURRENT_USER`, or `user@host` in any quoting as `` `user`@`host` ``; `None` if it doesn't parse.
+fn definer_sql(definer: &str) -> Option<String> {
+    if definer.eq_ignore_ascii_case("CURRENT_USER") || definer.eq_ignore_ascii_case("CURRENT_USER()") {
+        return Some("CURRENT_USER".to_string());
+    }
+    let (user, host) = definer.rsplit_once('@')?;
+    let host = account_part(host.trim()).filter(|h| !h.is_empty())?;
+    Some(format!("{}@{}", quote_identifier(&account_part(user.trim())?), quote_identifier(&host)))
+}
+
+/// Renders `CREATE [OR REPLACE] VIEW` for a spec.
+pub fn create_view_sql(db: &str, view: &ViewSpec) -> Result<String, String> {
+    if view.name.trim().is_empty() {
+        return Err("View name is required".to_string());
+    }
+    let select = view.select_sql.trim().trim_end_matches(';').trim();
+    let starts_ok = tokenize(select)
+        .iter()
+        .find(|t| t.kind != TokenKind::Whitespace && !t.is_comment())
+        .is_some_and(|t| t.is_word("SELECT") || t.is_word("WITH") || t.is_word("VALUES") || t.is_word("TABLE") || t.kind == TokenKind::OpenParen);
+    if !starts_ok {
+        return Err("A view must be defined by a SELECT statement".to_string());
+    }
+
+    let mut sql = String::from(if view.or_replace { "CREATE OR REPLACE" } else { "CREATE" });
+    if let Some(algorithm) = view.algorithm.as_deref().filter(|a| !a.is_empty()) {
+        let algorithm = algorithm.to_uppercase();
+        if !["UNDEFINED", "MERGE", "TEMPTABLE"].contains(&algorithm.as_str()) {
+            return Err(format!("Invalid algorithm: {}", algorithm));
+        }
+        sql.push_str(&format!(" ALGORITHM={}", algorithm));
+    }
+    if let Some(definer) = view.definer.as_deref().map(str::trim).filter(|d| !d.is_empty()) {
+        sql.push_str(&format!(" DEFINER={}", definer_sql(definer).ok_or(format!("Invalid definer: {}", definer))?));
+    }
+    if let Some(security) = view.security_type.as_deref().filter(|s| !s.is_empty()) {
+        let security = security.to_uppercase();
+        if security != "DEFINER" && security != "INVOKER" {
+            return Err(format!("Invalid SQL SECURITY: {}", security));
+        }
+        sql.push_str(&format!(" SQL SECURITY {}", security));
+    }
+
+    sql.push_str(&format!(" VIEW {}.{}", quote_identifier(db), quote_identifier(&view.name)));
+    if let Some(columns) = view.columns.as_ref().filter(|c| !c.is_empty()) {
+        sql.push_str(&format!(" ({})", columns.iter().map(|c| quote_identifier(c)).collect::<Vec<_>>().join(", ")));
+    }
+    sql.push_str(&format!(" AS\n{}", select));
+
+    match view.check_option.as_deref().map(str::to_uppercase).as_deref() {
+        None | Some("") | Some("NONE") => {}
+        Some("CASCADED") => sql.push_str("\nWITH CASCADED CHECK OPTION"),
+        Some("LOCAL") => sql.push_str("\nWITH LOCAL CHECK OPTION"),
+        Some(other) => return Err(format!("Invalid check option: {}", other)),
+    }
+    Ok(sql)
+}
+
+#[tauri::command]
+pub async fn preview_create_view(db: String, view: ViewSpec) -> Result<String, String> {
+    create_view_sql(&db, &view)
+}
+
+/// Creates a view, or replaces an existing one when `or_replace` is set. Returns the SQL that ran.
+#[tauri::command]
+pub async fn create_view(db: String, view: ViewSpec, state: State<'_, AppState>) -> Result<String, String> {
+    let sql = create_view_sql(&db, &view)?;
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+    conn.query_drop(&sql).await.map_err(|e| e.to_string())?;
+    Ok(sql)
+}
+
+/// Constructs that make a view non-updatable, found in its top-level SELECT.
+fn non_updatable_reasons(select_sql: &str, algorithm: &str) -> Vec<String> {
+    let mut reasons = Vec::new();
+    if algorithm.eq_ignore_ascii_case("TEMPTABLE") {
+        reasons.push("ALGORITHM=TEMPTABLE".to_string());
+    }
+    let aggregates = ["SUM", "COUNT", "MIN", "MAX", "AVG", "GROUP_CONCAT", "STD", "STDDEV", "VARIANCE", "BIT_AND", "BIT_OR", "BIT_XOR", "JSON_ARRAYAGG", "JSON_OBJECTAGG"];
+    let tokens: Vec<_> = tokenize(select_sql).into_iter().filter(|t| t.kind != TokenKind::Whitespace && !t.is_comment()).collect();
+    let mut depth = 0;
+    let mut in_select_list = false;
+    let mut add = |reason: &str| {
+        if !reasons.iter().any(|r| r == reason) {
+            reasons.push(reason.to_string());
+        }
+    };
+    for (i, tok) in tokens.iter().enumerate() {
+        match tok.kind {
+            TokenKind::OpenParen => {
+                if depth == 0 && in_select_list && tokens.get(i + 1).is_some_and(|t| t.is_word("SELECT")) {
+                    add("subquery in the select list");
+                }
+                depth += 1;
+            }
+            TokenKind::CloseParen => depth -= 1,
+            TokenKind::Word if depth == 0 => {
+                let word = tok.upper();
+                match word.as_str() {
+                    "SELECT" => in_select_list = true,
+                    "FROM" => in_select_list = false,
+                    "DISTINCT" => add("DISTINCT"),
+                    "GROUP" => add("GROUP BY"),
+                    "HAVING" => add("HAVING"),
+                    "UNION" | "INTERSECT" | "EXCEPT" => add(&word),
+                    "OVER" => add("window function"),
+                    _ if aggregates.contains(&word.as_str()) && tokens.get(i + 1).is_some_and(|t| t.kind == TokenKind::OpenParen) => {
+                        add("aggregate function")
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    if !tokens.iter().any(|t| t.is_word("FROM")) {
+        add("no underlying table");
+    }
+    reasons
+}
+
+#[tauri::command]
+pub async fn check_view_updatable(db: String, name: String, state: State<'_, AppState>) -> Result<ViewUpdatability, String> {
+    let definition = get_view_definition(db, name, state).await?;
+    let reasons = if definition.is_updatable {
+        Vec::new()
+    } else {
+        non_updatable_reasons(&definition.select_sql, &definition.algorithm)
+    };
+    Ok(ViewUpdatability { is_updatable: definition.is_updatable, reasons })
+}
+
+#[tauri::command]
+pub async fn drop_view(db: String, name: String, state: State<'_, AppState>) -> Result<(), String> {
+    let pool = {
+        let pool_guard = state.pool.lock().unwrap();
+        pool_guard.as_ref().cloned().ok_or("Not connected")?
+    };
+    let mut conn = pool.get_conn().await.map_err(|e| e.to_string())?;
+    conn.query_drop(format!("DROP VIEW {}.{}", quote_identifier(&db), quote_identifier(&name)))
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -12,6 +12,7 @@ pub fn run() {
         .plugin(tauri_plugin_dialog::init())
         .manage(AppState {
             pool: Mutex::new(None),
+            server_id: Mutex::new(None),
         })
         .invoke_handler(tauri::generate_handler![
             // Server
@@ -43,7 +44,54 @@ pub fn run() {
             commands::table::get_tables_html,
             commands::table::browse_table_html,
             commands::table::browse_table,
+            commands::table::browse_table_keyset,
             commands::table::update_cell,
+            commands::rows::insert_rows,
+            commands::rows::duplicate_rows,
+            commands::rows::delete_rows,
+            commands::rows::apply_changes,
+            commands::values::validate_rows,
+            commands::cells::get_cell_value,
+            commands::cells::save_cell_to_file,
+            commands::cells::load_file_into_cell,
+            commands::columns::preview_alter_columns,
+            commands::columns::alter_columns,
+            commands::columns::get_column_definition,
+            commands::table::preview_create_table,
+            commands::table::create_table,
+            commands::bulk::drop_tables,
+            commands::bulk::truncate_tables,
+            commands::bulk::maintain_tables,
+            commands::bulk::change_tables_engine,
+            commands::bulk::change_tables_collation,
+            commands::views::get_views,
+            commands::views::get_view_definition,
+            commands::views::preview_create_view,
+            commands::views::create_view,
+            commands::views::check_view_updatable,
+            commands::views::drop_view,
+            commands::ddl::get_object_ddl,
+            commands::comments::set_table_comment,
+            commands::comments::set_column_comment,
+            commands::comments::set_index_comment,
+            commands::comments::set_routine_comment,
+            commands::constraints::get_check_constraints,
+            commands::constraints::add_check_constraint,
+            commands::constraints::drop_check_constraint,
+            commands::partitions::get_partitions,
+            commands::partitions::preview_partition_table,
+            commands::partitions::partition_table,
+            commands::partitions::remove_partitioning,
+            commands::partitions::add_partitions,
+            commands::partitions::drop_partitions,
+            commands::partitions::truncate_partitions,
+            commands::partitions::reorganize_partitions,
+            commands::partitions::exchange_partition,
+            commands::partitions::partition_maintenance,
+            commands::profiling::profile_table,
+            commands::auto_increment::get_auto_increment_report,
+            commands::auto_increment::set_auto_increment,
+            commands::type_change::analyze_type_change,
             commands::table::get_columns,
             commands::table::get_table_count,
             commands::table::rename_table,
@@ -57,6 +105,9 @@ pub fn run() {
             commands::server::delete_server_local,
             commands::query::execute_query,
             commands::query::execute_query_html,
+            commands::formatter::format_sql,
+            commands::lint::lint_sql,
+            commands::compare::compare_queries,
 
             // Import/Export
             commands::import_export::export_database,
@@ -64,6 +115,7 @@ pub fn run() {
             commands::import_export::import_sql,
             commands::import_export::get_csv_preview,
             commands::import_export::import_csv,
+            commands::import_export::export_query_result,
 
             // Relations
             commands::relations::get_foreign_keys,
@@ -105,6 +157,24 @@ pub fn run() {
             commands::snippets::save_snippet,
             commands::snippets::save_snippet,
             commands::snippets::delete_snippet,
+            commands::snippets::get_snippet_variables,
+            commands::snippets::render_snippet,
+            commands::snippets::execute_snippet,
+            commands::snippets::search_snippets,
+            commands::snippets::get_snippet_library_index,
+            commands::snippets::find_duplicate_snippets,
+            commands::snippets::export_snippets,
+            commands::snippets::import_snippets,
+            // Notebooks
+            commands::notebooks::get_notebooks,
+            commands::notebooks::get_notebook,
+            commands::notebooks::save_notebook,
+            commands::notebooks::delete_notebook,
+            commands::notebooks::run_notebook_cell,
+            commands::notebooks::run_notebook,
+            commands::notebooks::clear_notebook_outputs,
+            commands::notebooks::export_notebook,
+            commands::notebooks::import_notebook,
 
             // Preferences
             commands::preferences::load_preferences,
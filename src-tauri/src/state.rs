@@ -4,6 +4,7 @@ use std::sync::Mutex;
 
 pub struct AppState {
     pub pool: Mutex<Option<Pool>>,
+    pub server_id: Mutex<Option<String>>, // saved server the pool is connected to, if known
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -25,6 +26,8 @@ pub struct ServerConfig {
 
 #[derive(Serialize, Deserialize)]
 pub struct DbConfig {
+    #[serde(default)]
+    pub id: Option<String>, // saved server id
     pub host: String,
     pub user: String,
     pub pass: String,
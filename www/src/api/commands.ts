@@ -27,9 +27,10 @@ export interface Table {
     rows: number;
     size: number;
     overhead: number;
-    // type: string; // Removed as not in Rust struct TableInfo
+    table_type: string; // 'BASE TABLE', 'VIEW', 'SYSTEM VIEW' or 'SEQUENCE'
     engine: string;
     collation: string;
+    comment: string;
 }
 
 export interface BrowseResult {
@@ -45,7 +46,324 @@ export interface BrowseResultRaw {
     columns: string[];
     rows: any[][];
     total_rows: number;
+    total_is_estimate: boolean;
     primary_key?: string;
+    key_columns: string[];
+    key_kind: 'primary' | 'unique' | 'none';
+}
+
+export interface InsertRowsResult {
+    inserted: number;
+    keys: Record<string, any>[];
+}
+
+export interface ChangeSet {
+    inserts?: Record<string, any>[];
+    updates?: { original: Record<string, any>, values: Record<string, any> }[];
+    deletes?: Record<string, any>[];
+}
+
+export interface ChangeSetResult {
+    committed: boolean;
+    inserted_keys: Record<string, any>[];
+    updated: number;
+    deleted: number;
+    conflicts: { operation: 'update' | 'delete', index: number, reason: 'modified' | 'deleted', current?: Record<string, any> }[];
+    errors: CellError[];
+    warnings: string[];
+}
+
+export interface CellError {
+    operation: string;
+    row: number;
+    column: string;
+    message: string;
+}
+
+export interface ColumnDefinition {
+    name: string;
+    data_type: string;
+    length?: string;
+    values?: string[];
+    unsigned?: boolean;
+    zerofill?: boolean;
+    nullable?: boolean;
+    default_kind?: 'none' | 'null' | 'value' | 'current_timestamp' | 'expression';
+    default_value?: string;
+    on_update_current_timestamp?: boolean;
+    auto_increment?: boolean;
+    charset?: string;
+    collation?: string;
+    comment?: string;
+    generated_expression?: string;
+    generated_stored?: boolean;
+    first?: boolean;
+    after?: string;
+}
+
+export interface TableDefinition {
+    name: string;
+    columns: ColumnDefinition[];
+    primary_key?: string[];
+    indexes?: { name?: string, kind?: 'INDEX' | 'UNIQUE' | 'FULLTEXT' | 'SPATIAL', columns: string[] }[];
+    foreign_keys?: { name?: string, columns: string[], ref_db?: string, ref_table: string, ref_columns: string[], on_delete?: string, on_update?: string }[];
+    checks?: { name?: string, expression: string }[];
+    engine?: string;
+    charset?: string;
+    collation?: string;
+    row_format?: string;
+    comment?: string;
+    auto_increment?: number;
+    if_not_exists?: boolean;
+}
+
+export interface BulkOptions {
+    ignore_foreign_keys?: boolean;
+    stop_on_error?: boolean;
+}
+
+export interface TableOperationResult {
+    table: string;
+    ok: boolean;
+    message?: string;
+    rows: string[][];
+    duration_ms: number;
+}
+
+export interface BulkReport {
+    operation: string;
+    total: number;
+    succeeded: number;
+    failed: number;
+    skipped: number;
+    results: TableOperationResult[];
+    duration_ms: number;
+}
+
+export interface ViewInfo {
+    name: string;
+    definer: string;
+    security_type: string;
+    check_option: string;
+    is_updatable: boolean;
+}
+
+export interface ViewDefinition extends ViewInfo {
+    algorithm: string;
+    select_sql: string;
+    create_sql: string;
+    character_set_client: string;
+    collation_connection: string;
+}
+
+export interface ViewSpec {
+    name: string;
+    select_sql: string;
+    columns?: string[];
+    algorithm?: 'UNDEFINED' | 'MERGE' | 'TEMPTABLE';
+    definer?: string;
+    security_type?: 'DEFINER' | 'INVOKER';
+    check_option?: 'NONE' | 'CASCADED' | 'LOCAL';
+    or_replace?: boolean;
+}
+
+export interface DdlOptions {
+    strip_definer?: boolean;
+    strip_auto_increment?: boolean;
+    format?: boolean;
+    format_options?: FormatOptions;
+}
+
+export interface ObjectDdl {
+    object_type: string;
+    name: string;
+    ddl: string;
+    sql_mode?: string;
+}
+
+export interface SubpartitionInfo {
+    name: string;
+    rows: number;
+    data_length: number;
+    index_length: number;
+}
+
+export interface PartitionInfo {
+    name: string;
+    ordinal: number;
+    description: string | null;
+    rows: number;
+    data_length: number;
+    index_length: number;
+    data_free: number;
+    comment: string;
+    subpartitions: SubpartitionInfo[];
+}
+
+export interface TablePartitions {
+    is_partitioned: boolean;
+    method: string | null;
+    expression: string | null;
+    subpartition_method: string | null;
+    subpartition_expression: string | null;
+    partitions: PartitionInfo[];
+}
+
+export interface PartitionDefinition {
+    name: string;
+    less_than?: string;
+    values_in?: string;
+    comment?: string;
+}
+
+export interface PartitionScheme {
+    method: string;
+    expression: string;
+    partitions?: PartitionDefinition[];
+    count?: number;
+}
+
+export interface ProfileOptions {
+    columns?: string[];
+    sample_rows?: number;
+    exact_distinct?: boolean;
+    top_n?: number;
+    buckets?: number;
+    server_histograms?: boolean;
+}
+
+export interface ValueFrequency {
+    value: any;
+    count: number;
+}
+
+export interface HistogramBucket {
+    lower: any;
+    upper: any;
+    frequency: number;
+    count: number | null;
+    distinct: number | null;
+}
+
+export interface ColumnProfile {
+    name: string;
+    data_type: string;
+    null_count: number;
+    null_ratio: number;
+    distinct_count: number;
+    distinct_exact: boolean;
+    min: any;
+    max: any;
+    avg_length: number | null;
+    top_values: ValueFrequency[];
+    histogram: HistogramBucket[];
+    histogram_source: 'server' | 'computed' | null;
+}
+
+export interface TableProfile {
+    rows: number;
+    sampled: boolean;
+    columns: ColumnProfile[];
+}
+
+export interface AutoIncrementUsage {
+    db: string;
+    table: string;
+    column: string;
+    column_type: string;
+    max_value: number;
+    next_value: number | null;
+    used_percent: number;
+    growth_per_day: number | null;
+    days_left: number | null;
+    exhausted_at: string | null;
+    samples: number;
+}
+
+export interface ValueChange {
+    from: any;
+    to: any;
+}
+
+export interface ConversionIssue {
+    kind: 'truncated' | 'rounded' | 'rejected' | 'zeroed' | 'nulled' | 'converted';
+    count: number;
+    message: string;
+    examples: ValueChange[];
+}
+
+export interface TypeChangeReport {
+    column: string;
+    current_type: string;
+    target_type: string;
+    sql_mode: string;
+    strict: boolean;
+    rows_checked: number;
+    sampled: boolean;
+    issues: ConversionIssue[];
+    algorithm: 'INSTANT' | 'INPLACE' | 'COPY';
+    algorithm_reason: string;
+    table_rows: number;
+    table_bytes: number;
+    alter_sql: string;
+}
+
+export interface ColumnChange {
+    action: 'add' | 'modify' | 'rename' | 'drop';
+    column?: string;
+    definition?: ColumnDefinition;
+    new_name?: string;
+}
+
+export interface CellValue {
+    is_null: boolean;
+    total_bytes: number;
+    offset: number;
+    length: number;
+    complete: boolean;
+    content: { kind: string, mime: string, encoding?: string };
+    text?: string;
+    hex?: string;
+    base64?: string;
+}
+
+export interface SortSpec {
+    column: string;
+    direction?: 'asc' | 'desc';
+    nulls?: 'first' | 'last';
+}
+
+export interface FilterCondition {
+    column: string;
+    operator: '=' | '!=' | '<' | '<=' | '>' | '>=' | 'LIKE' | 'NOT LIKE' | 'IN' | 'NOT IN' | 'BETWEEN' | 'IS NULL' | 'IS NOT NULL' | 'REGEXP' | 'NOT REGEXP';
+    value?: any;
+    values?: any[];
+}
+
+export interface BrowseOptions {
+    sort?: SortSpec[];
+    filters?: FilterCondition[];
+    count_mode?: 'exact' | 'estimate' | 'none';
+}
+
+export interface KeysetOptions {
+    key_columns?: string[];
+    direction?: 'first' | 'next' | 'prev' | 'last' | 'jump';
+    cursor?: any[];
+    filters?: FilterCondition[];
+    count_mode?: 'exact' | 'estimate' | 'none';
+}
+
+export interface KeysetPage {
+    columns: string[];
+    rows: any[][];
+    key_columns: string[];
+    first_key?: any[];
+    last_key?: any[];
+    has_next: boolean;
+    has_prev: boolean;
+    total_rows?: number;
+    total_is_estimate: boolean;
 }
 
 export interface SearchResult {
@@ -59,6 +377,7 @@ export interface Routine {
     data_type: string;
     created: string;
     last_altered: string;
+    comment: string;
 }
 
 export interface PrivilegeMatrix {
@@ -74,12 +393,77 @@ export interface ColumnInfo {
     key: string;
     default: string | null;
     extra: string;
+    comment: string;
+    generation_expression: string | null;
+    generated_kind: 'VIRTUAL' | 'STORED' | null;
+}
+
+export interface CheckConstraint {
+    name: string;
+    expression: string;
+    enforced?: boolean;
 }
 
 export interface QueryOptions {
     // Add fields if QueryOptions struct in Rust has them
 }
 
+export interface FormatOptions {
+    keyword_case?: 'upper' | 'lower' | 'preserve';
+    indent_size?: number;
+    use_tabs?: boolean;
+    line_width?: number;
+    comma_position?: 'trailing' | 'leading';
+    align?: boolean;
+    minify?: boolean;
+}
+
+export interface LintWarning {
+    rule: string;
+    severity: 'error' | 'warning' | 'info';
+    message: string;
+    start: number;
+    end: number;
+    line: number;
+    column: number;
+    end_line: number;
+    end_column: number;
+}
+
+export interface CompareSource {
+    sql: string;
+    db?: string;
+    connection?: any; // same shape as the connect_db config
+}
+
+export interface CompareOptions {
+    key_columns?: string[];
+    ignore_columns?: string[];
+    max_rows?: number;
+}
+
+export interface CompareResult {
+    columns: string[];
+    left_only_columns: string[];
+    right_only_columns: string[];
+    key_columns: string[];
+    added: any[][];
+    removed: any[][];
+    changed: { key: any[], left: any[], right: any[], diffs: { column: string, left: any, right: any }[] }[];
+    summary: {
+        left_rows: number;
+        right_rows: number;
+        unchanged: number;
+        added: number;
+        removed: number;
+        changed: number;
+        duplicate_keys_left: number;
+        duplicate_keys_right: number;
+    };
+    truncated: boolean;
+    identical: boolean;
+}
+
 export interface CsvPreview {
     headers: string[];
     rows: string[][];
@@ -101,6 +485,16 @@ export interface ExportOptions {
     data_insertion_mode: string; // "INSERT", "INSERT IGNORE", "REPLACE"
 }
 
+export interface QueryExportOptions {
+    format: 'csv' | 'tsv' | 'json' | 'ndjson' | 'markdown' | 'html' | 'sql' | 'xlsx';
+    include_headers?: boolean;
+    null_value?: string;
+    delimiter?: string;
+    line_ending?: 'lf' | 'crlf';
+    table_name?: string;
+    rows_per_insert?: number;
+}
+
 // Map of Command Name -> [ArgsType, ReturnType]
 export type TauriCommands = {
     // Server
@@ -130,21 +524,87 @@ export type TauriCommands = {
     // Table
     'get_tables': [{ db: string }, Table[]];
     'get_tables_html': [{ db: string, table?: string }, any]; // Returns TablesResultHtml
-    'browse_table': [{ db: string, table: string, page: number, limit: number }, BrowseResultRaw];
-    'browse_table_html': [{ db: string, table: string, page: number, limit: number }, BrowseResult];
-    'update_cell': [{ db: string, table: string, column: string, value: any, primary_key_col: string, primary_key_val: any }, void];
+    'browse_table': [{ db: string, table: string, page: number, limit: number, options?: BrowseOptions }, BrowseResultRaw];
+    'browse_table_html': [{ db: string, table: string, page: number, limit: number, options?: BrowseOptions }, BrowseResult];
+    'browse_table_keyset': [{ db: string, table: string, limit: number, options?: KeysetOptions }, KeysetPage];
+    'update_cell': [{ db: string, table: string, column: string, value: any, primaryKeyCol?: string, primaryKeyVal?: any, row?: Record<string, any> }, { affected_rows: number, warning?: string }];
+    'insert_rows': [{ db: string, table: string, rows: Record<string, any>[] }, InsertRowsResult];
+    'duplicate_rows': [{ db: string, table: string, rows: Record<string, any>[], overrides?: Record<string, any>[] }, InsertRowsResult];
+    'delete_rows': [{ db: string, table: string, rows: Record<string, any>[] }, { deleted: number, not_found: number, warnings: string[] }];
+    'apply_changes': [{ db: string, table: string, changes: ChangeSet }, ChangeSetResult];
+    'validate_rows': [{ db: string, table: string, rows: Record<string, any>[] }, CellError[]];
+    'get_cell_value': [{ db: string, table: string, column: string, row: Record<string, any>, offset?: number, length?: number }, CellValue];
+    'save_cell_to_file': [{ db: string, table: string, column: string, row: Record<string, any>, filePath: string }, number];
+    'load_file_into_cell': [{ db: string, table: string, column: string, row: Record<string, any>, filePath: string }, { bytes: number, affected_rows: number, warning?: string }];
+    'preview_alter_columns': [{ db: string, table: string, changes: ColumnChange[] }, string];
+    'alter_columns': [{ db: string, table: string, changes: ColumnChange[] }, string];
+    'get_column_definition': [{ db: string, table: string, column: string }, ColumnDefinition];
+    'preview_create_table': [{ db: string, definition: TableDefinition }, string];
+    'create_table': [{ db: string, definition: TableDefinition }, string];
     'get_columns': [{ db: string, table: string }, ColumnInfo[]]; 
     'get_table_count': [{ db: string, table: string }, number];
     'rename_table': [{ db: string, table: string, new_name: string, new_db?: string }, void];
     'truncate_table': [{ db: string, table: string }, void];
     'copy_table': [{ db: string, table: string, new_db: string, new_table: string, with_data: boolean }, void];
     'table_maintenance': [{ db: string, table: string, op: string }, string[][]];
+    'drop_tables': [{ db: string, tables: string[], options?: BulkOptions }, BulkReport];
+    'truncate_tables': [{ db: string, tables: string[], options?: BulkOptions }, BulkReport];
+    'maintain_tables': [{ db: string, tables: string[], op: string, options?: BulkOptions }, BulkReport];
+    'change_tables_engine': [{ db: string, tables: string[], engine: string, options?: BulkOptions }, BulkReport];
+    'change_tables_collation': [{ db: string, tables: string[], collation: string, convert?: boolean, options?: BulkOptions }, BulkReport];
+    'get_views': [{ db: string }, ViewInfo[]];
+    'get_view_definition': [{ db: string, name: string }, ViewDefinition];
+    'preview_create_view': [{ db: string, view: ViewSpec }, string];
+    'create_view': [{ db: string, view: ViewSpec }, string];
+    'check_view_updatable': [{ db: string, name: string }, { is_updatable: boolean, reasons: string[] }];
+    'drop_view': [{ db: string, name: string }, void];
+    'get_object_ddl': [{ db: string, objectType: 'table' | 'view' | 'trigger' | 'event' | 'procedure' | 'function' | 'sequence', name: string, options?: DdlOptions }, ObjectDdl];
+    'set_table_comment': [{ db: string, table: string, comment: string }, void];
+    'set_column_comment': [{ db: string, table: string, column: string, comment: string }, string];
+    'set_index_comment': [{ db: string, table: string, index: string, comment: string }, string];
+    'set_routine_comment': [{ db: string, name: string, routineType: string, comment: string }, void];
+    'get_check_constraints': [{ db: string, table: string }, CheckConstraint[]];
+    'add_check_constraint': [{ db: string, table: string, name?: string, expression: string, enforced?: boolean }, string];
+    'drop_check_constraint': [{ db: string, table: string, name: string }, void];
+    'get_partitions': [{ db: string, table: string }, TablePartitions];
+    'preview_partition_table': [{ db: string, table: string, scheme: PartitionScheme }, string];
+    'partition_table': [{ db: string, table: string, scheme: PartitionScheme }, string];
+    'remove_partitioning': [{ db: string, table: string }, string];
+    'add_partitions': [{ db: string, table: string, partitions: PartitionDefinition[], count?: number }, string];
+    'drop_partitions': [{ db: string, table: string, names: string[] }, string];
+    'truncate_partitions': [{ db: string, table: string, names: string[] }, string];
+    'reorganize_partitions': [{ db: string, table: string, names: string[], into: PartitionDefinition[] }, string];
+    'exchange_partition': [{ db: string, table: string, partition: string, withTable: string, withDb?: string, validate?: boolean }, string];
+    'partition_maintenance': [{ db: string, table: string, op: string, names: string[] }, string[][]];
+    'profile_table': [{ db: string, table: string, options?: ProfileOptions }, TableProfile];
+    'get_auto_increment_report': [{ db?: string }, AutoIncrementUsage[]];
+    'set_auto_increment': [{ db: string, table: string, value?: number }, string];
+    'analyze_type_change': [{ db: string, table: string, column: string, target: ColumnDefinition, sampleRows?: number }, TypeChangeReport];
     'global_search': [{ db?: string, term: string }, SearchResult[]];
 
     // Snippets
     'get_snippets': [undefined, Snippet[]];
     'save_snippet': [{ snippet: Snippet }, Snippet[]];
     'delete_snippet': [{ id: string }, Snippet[]];
+    'get_snippet_variables': [{ sql: string, db?: string }, SnippetVariable[]];
+    'render_snippet': [{ sql: string, values: Record<string, any> }, { sql: string, params: any[] }];
+    'execute_snippet': [{ sql: string, values: Record<string, any>, db?: string }, QueryResult];
+    'search_snippets': [{ filter: SnippetFilter }, Snippet[]];
+    'get_snippet_library_index': [undefined, { folders: string[], tags: string[] }];
+    'find_duplicate_snippets': [undefined, Snippet[][]];
+    'export_snippets': [{ path: string, format: 'json' | 'folder', ids?: string[] }, number];
+    'import_snippets': [{ path: string, allowDuplicates?: boolean }, { imported: number, updated: number, skipped_duplicates: string[] }];
+
+    // Notebooks
+    'get_notebooks': [undefined, NotebookSummary[]];
+    'get_notebook': [{ id: string }, Notebook];
+    'save_notebook': [{ notebook: Notebook }, Notebook];
+    'delete_notebook': [{ id: string }, void];
+    'run_notebook_cell': [{ notebookId: string, cellId: string, maxRows?: number }, NotebookCell];
+    'run_notebook': [{ notebookId: string, options?: { max_rows?: number, stop_on_error?: boolean, from_cell?: string } }, Notebook];
+    'clear_notebook_outputs': [{ notebookId: string }, Notebook];
+    'export_notebook': [{ id: string, filePath: string, includeOutputs?: boolean }, void];
+    'import_notebook': [{ filePath: string }, Notebook];
 
     // AI
     'get_ai_config': [undefined, AIConfig];
@@ -155,6 +615,9 @@ export type TauriCommands = {
     // Query
     'execute_query': [{ sql: string, db?: string, options?: QueryOptions }, QueryResult];
     'execute_query_html': [{ sql: string, db?: string, options?: QueryOptions }, any];
+    'format_sql': [{ sql: string, options?: FormatOptions }, string];
+    'lint_sql': [{ sql: string, db?: string }, LintWarning[]];
+    'compare_queries': [{ left: CompareSource, right: CompareSource, options?: CompareOptions }, CompareResult];
 
     // Import/Export (Placeholder)
     'export_database': [{ db: string, file: string, options: ExportOptions }, void];
@@ -162,6 +625,7 @@ export type TauriCommands = {
     'import_sql': [{ db: string, sql: string }, void];
     'get_csv_preview': [{ filePath: string, delimiter: string }, CsvPreview];
     'import_csv': [{ db: string, table: string, filePath: string, options: CsvImportOptions }, number];
+    'export_query_result': [{ sql: string, db?: string, filePath: string, options: QueryExportOptions }, number];
 
     // Relations (Placeholder)
     'get_foreign_keys': [{ db: string, table: string }, any[]];
@@ -217,6 +681,69 @@ export interface Snippet {
     sql: string;
     description?: string;
     created_at: string;
+    folder?: string;
+    tags?: string[];
+    server_id?: string;
+    database?: string;
+    favorite?: boolean;
+    updated_at?: string;
+}
+
+export interface SnippetFilter {
+    query?: string;
+    folder?: string;
+    tags?: string[];
+    server_id?: string;
+    database?: string;
+    favorites_only?: boolean;
+}
+
+export interface CellOutput {
+    columns: string[];
+    rows: any[][];
+    total_rows: number;
+    truncated: boolean;
+    error?: string;
+    executed_at: string;
+    duration_ms: number;
+}
+
+export interface NotebookCell {
+    id: string;
+    cell_type: 'sql' | 'markdown';
+    source: string;
+    output?: CellOutput;
+}
+
+export interface Notebook {
+    id: string;
+    name: string;
+    description?: string;
+    server_id?: string;
+    database?: string;
+    cells: NotebookCell[];
+    created_at: string;
+    updated_at?: string;
+}
+
+export interface NotebookSummary {
+    id: string;
+    name: string;
+    description?: string;
+    server_id?: string;
+    database?: string;
+    cell_count: number;
+    created_at: string;
+    updated_at?: string;
+}
+
+export interface SnippetVariable {
+    name: string;
+    var_type: 'string' | 'int' | 'float' | 'bool' | 'date' | 'datetime' | 'enum';
+    default?: string;
+    options: string[];
+    options_query?: string;
+    resolved_default?: string;
 }
 
 export interface AIConfig {
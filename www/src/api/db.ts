@@ -1,5 +1,5 @@
 import { invoke } from '@tauri-apps/api/core';
-import { TauriCommands, CommandName } from './commands';
+import { TauriCommands, CommandName, BrowseOptions } from './commands';
 
 // Re-export types for consumers
 export type { Database, Table, BrowseResult, SavedServer } from './commands';
@@ -38,16 +38,16 @@ export const dbApi = {
         return safeInvoke('get_columns', { db, table });
     },
 
-    browseTable: async (db: string, table: string, page: number, limit: number) => {
-        return safeInvoke('browse_table_html', { db, table, page, limit });
+    browseTable: async (db: string, table: string, page: number, limit: number, options?: BrowseOptions) => {
+        return safeInvoke('browse_table_html', { db, table, page, limit, options });
     },
 
-    browseTableRaw: async (db: string, table: string, page: number, limit: number) => {
-        return safeInvoke('browse_table', { db, table, page, limit });
+    browseTableRaw: async (db: string, table: string, page: number, limit: number, options?: BrowseOptions) => {
+        return safeInvoke('browse_table', { db, table, page, limit, options });
     },
 
-    updateCell: async (db: string, table: string, column: string, value: any, pk_col: string, pk_val: any) => {
-        return safeInvoke('update_cell', { db, table, column, value, primary_key_col: pk_col, primary_key_val: pk_val });
+    updateCell: async (db: string, table: string, column: string, value: any, row: Record<string, any>) => {
+        return safeInvoke('update_cell', { db, table, column, value, row });
     },
 
     executeQuery: async (sql: string, db?: string) => {
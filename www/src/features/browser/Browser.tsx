@@ -88,18 +88,17 @@ export function Browser() {
 
     // 3. Update Cell Mutation
     const updateMutation = useMutation({
-        mutationFn: (args: { column: string, value: any, pkVal: any }) => 
-            dbApi.updateCell(currentDb!, currentTable!, args.column, args.value, browseData?.primary_key!, args.pkVal),
-        onSuccess: () => {
+        mutationFn: (args: { column: string, value: any, row: Record<string, any> }) => 
+            dbApi.updateCell(currentDb!, currentTable!, args.column, args.value, args.row),
+        onSuccess: (result) => {
+            if (result?.warning) {
+                console.warn(result.warning);
+            }
             // We could update cache locally for instant feedback, but refetch is safer for now
             refetch();
         }
     });
 
-    const pkIndex = React.useMemo(() => {
-        if (!browseData?.primary_key || !browseData?.columns) return -1;
-        return browseData.columns.indexOf(browseData.primary_key);
-    }, [browseData]);
 
     if (!currentDb) {
         return <div className="p-8 text-center text-white/30">Select a database to browse</div>;
@@ -187,11 +186,11 @@ export function Browser() {
                                             {browseData?.columns.map(col => (
                                                 <th key={col} className={cn(
                                                     "p-3 text-[11px] font-bold uppercase tracking-wider text-text-muted border-r border-border/50",
-                                                    col === browseData.primary_key && "text-primary"
+                                                    browseData.key_kind !== 'none' && browseData.key_columns.includes(col) && "text-primary"
                                                 )}>
                                                     <div className="flex items-center gap-2">
                                                         {col}
-                                                        {col === browseData.primary_key && <span className="text-[8px] bg-primary/20 text-primary px-1 rounded">PK</span>}
+                                                        {browseData.key_kind === 'primary' && browseData.key_columns.includes(col) && <span className="text-[8px] bg-primary/20 text-primary px-1 rounded">PK</span>}
                                                     </div>
                                                 </th>
                                             ))}
@@ -199,7 +198,8 @@ export function Browser() {
                                     </thead>
                                     <tbody className="divide-y divide-border/30">
                                         {browseData?.rows.map((row, rowIndex) => {
-                                            const pkVal = pkIndex !== -1 ? row[pkIndex] : null;
+                                            // Original values identify the row (all key columns, or every column for keyless tables)
+                                            const originalRow = Object.fromEntries(browseData.columns.map((col, i) => [col, row[i]]));
                                             return (
                                                 <tr key={rowIndex} className="hover:bg-white/[0.02] transition-colors group">
                                                     <td className="p-3 text-[10px] text-center opacity-30 font-mono bg-black/5">
@@ -210,15 +210,11 @@ export function Browser() {
                                                             <EditableCell 
                                                                 value={val} 
                                                                 onSave={(newVal) => {
-                                                                    if (browseData.primary_key && pkVal !== null) {
-                                                                        updateMutation.mutate({ 
-                                                                            column: browseData.columns[cellIndex], 
-                                                                            value: newVal, 
-                                                                            pkVal 
-                                                                        });
-                                                                    } else {
-                                                                        console.warn('Cannot update: Primary Key not found for this table/row');
-                                                                    }
+                                                                    updateMutation.mutate({ 
+                                                                        column: browseData.columns[cellIndex], 
+                                                                        value: newVal, 
+                                                                        row: originalRow 
+                                                                    });
                                                                 }}
                                                             />
                                                         </td>